@@ -333,7 +333,7 @@ function SplitCard({ setModalVisible, setResult }: SplitCardProps) {
     );
 
     const [playerProfilePDA] = PublicKey.findProgramAddressSync(
-      [publicKey.toBuffer()],
+      [Buffer.from("profile"), gameVaultPDA.toBuffer(), publicKey.toBuffer()],
       programId
     );
 
@@ -342,17 +342,53 @@ function SplitCard({ setModalVisible, setResult }: SplitCardProps) {
       programId
     );
 
+    const [profileTombstonePDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("tombstone"), gameVaultPDA.toBuffer(), publicKey.toBuffer()],
+      programId
+    );
+
+    const [legacyProfilePDA] = PublicKey.findProgramAddressSync(
+      [publicKey.toBuffer()],
+      programId
+    );
+
+    const [treasuryPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury"), gameVaultPDA.toBuffer()],
+      programId
+    );
+
     let txSignature: string = "";
     try {
+      // Profiles from before they were scoped to the vault have to be moved over before playing
+      const migrateInstructions = (await connection.getAccountInfo(
+        legacyProfilePDA
+      ))
+        ? [
+            await splitOrSteal.methods
+              .migrateProfile()
+              .accounts({
+                player: publicKey,
+                payer: publicKey,
+                gameVault: gameVaultPDA,
+                legacyProfile: legacyProfilePDA,
+                playerProfile: playerProfilePDA,
+                systemProgram: anchor.web3.SystemProgram.programId,
+              })
+              .instruction(),
+          ]
+        : [];
       txSignature = await splitOrSteal.methods
         .playGame(choiceObj, stakeAmount)
         .accounts({
           player: publicKey,
+          payer: publicKey,
           playerProfile: playerProfilePDA,
+          profileTombstone: profileTombstonePDA,
+          legacyProfile: legacyProfilePDA,
           playerTokenAccount: playerTokenAccountPDA,
           gameVault: gameVaultPDA,
           gameVaultTokenAccount: gameVaultTokenAccountPDA,
-          creator: wallet.publicKey,
+          treasury: treasuryPDA,
           mint: TOKEN_MINT,
           slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
           instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
@@ -365,8 +401,9 @@ function SplitCard({ setModalVisible, setResult }: SplitCardProps) {
             microLamports: 20_000,
           }),
           anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({
-            units: 140_000,
+            units: 140_000 + migrateInstructions.length * 30_000,
           }),
+          ...migrateInstructions,
         ])
         .rpc({ skipPreflight: true });
       console.log("Game played successfully");
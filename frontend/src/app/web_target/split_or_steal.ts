@@ -53,21 +53,36 @@ export type SplitOrSteal = {
       "accounts": [
         {
           "name": "player",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "playerProfile",
+          "name": "gameVault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "playerTokenAccount",
+          "name": "playerProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "gameVault",
+          "name": "profileTombstone",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "legacyProfile",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "playerTokenAccount",
           "isMut": true,
           "isSigner": false
         },
@@ -77,7 +92,7 @@ export type SplitOrSteal = {
           "isSigner": false
         },
         {
-          "name": "creator",
+          "name": "treasury",
           "isMut": true,
           "isSigner": false
         },
@@ -110,6 +125,84 @@ export type SplitOrSteal = {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "jackpotTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "referrerProfile",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "referrerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "team",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "instructionAllowlist",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "sessionKey",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true
+        },
+        {
+          "name": "session",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "integrator",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "integratorAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true
+        },
+        {
+          "name": "integratorTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "playerHistory",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "linkedProfile",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
         }
       ],
       "args": [
@@ -124,72 +217,5436 @@ export type SplitOrSteal = {
           "type": "u16"
         }
       ]
-    }
-  ],
-  "accounts": [
+    },
     {
-      "name": "gameVault",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "creator",
-            "type": "publicKey"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "tokenBump",
-            "type": "u8"
-          },
-          {
-            "name": "initialTokens",
-            "type": "u64"
-          },
-          {
-            "name": "mintRemaining",
-            "type": "u64"
-          },
-          {
-            "name": "burnedAmount",
-            "type": "u64"
+      "name": "playBatch",
+      "accounts": [
+        {
+          "name": "player",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "playerProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileTombstone",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "legacyProfile",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "playerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gameVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "slotHashes",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "jackpotTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "referrerProfile",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "referrerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "team",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "instructionAllowlist",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "sessionKey",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true
+        },
+        {
+          "name": "session",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "integrator",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "integratorAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true
+        },
+        {
+          "name": "integratorTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "playerHistory",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "linkedProfile",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "games",
+          "type": {
+            "vec": {
+              "defined": "BatchGame"
+            }
+          }
+        }
+      ],
+      "returns": {
+        "vec": {
+          "defined": "GameResult"
+        }
+      }
+    },
+    {
+      "name": "playGameCpi",
+      "accounts": [
+        {
+          "name": "player",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "playerProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileTombstone",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "legacyProfile",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "playerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gameVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "slotHashes",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "jackpotTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "referrerProfile",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "referrerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "team",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "instructionAllowlist",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "sessionKey",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true
+        },
+        {
+          "name": "session",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "integrator",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "integratorAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true
+        },
+        {
+          "name": "integratorTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "playerHistory",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "linkedProfile",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "choice",
+          "type": {
+            "defined": "Choice"
+          }
+        },
+        {
+          "name": "stakeAmount",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "updateConfig",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "config",
+          "type": {
+            "defined": "VaultConfig"
+          }
+        }
+      ]
+    },
+    {
+      "name": "migrateVault",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setIntegrator",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "integrator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "programId",
+          "type": "publicKey"
+        },
+        {
+          "name": "revenueTokenAccount",
+          "type": "publicKey"
+        },
+        {
+          "name": "revenueShareBps",
+          "type": "u16"
+        },
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setVaultPaused",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "bool"
+        },
+        {
+          "name": "resetAnomalyStats",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "initializeJackpot",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gameVaultTokenAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "jackpotTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeTreasury",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawTreasury",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "startSeason",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "seasonArchive",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createTeam",
+      "accounts": [
+        {
+          "name": "leader",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "leaderProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "team",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "maxMembers",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "joinTeam",
+      "accounts": [
+        {
+          "name": "player",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "playerProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "team",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "leaveTeam",
+      "accounts": [
+        {
+          "name": "player",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "playerProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "team",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateProfile",
+      "accounts": [
+        {
+          "name": "player",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "legacyProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "playerProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "linkWallet",
+      "accounts": [
+        {
+          "name": "primary",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "primaryProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "secondary",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "secondaryProfile",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeTeamLeaderboard",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "teamLeaderboard",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateTeamLeaderboard",
+      "accounts": [
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "teamLeaderboard",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "team",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setInstructionAllowlist",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructionAllowlist",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "programs",
+          "type": {
+            "vec": {
+              "defined": "AllowedProgram"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeAchievementCollection",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "achievementCollection",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimAchievement",
+      "accounts": [
+        {
+          "name": "player",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "playerProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "achievementMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "achievementCollection",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "achievementTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "achievement",
+          "type": {
+            "defined": "Achievement"
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimDeposit",
+      "accounts": [
+        {
+          "name": "player",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "playerProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "depositor",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeProfile",
+      "accounts": [
+        {
+          "name": "player",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "playerProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileTombstone",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createSession",
+      "accounts": [
+        {
+          "name": "player",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "session",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "playerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionKey",
+          "type": "publicKey"
+        },
+        {
+          "name": "expirySlot",
+          "type": "u64"
+        },
+        {
+          "name": "maxTotalStake",
+          "type": "u64"
+        },
+        {
+          "name": "maxGames",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "setProfileMetadata",
+      "accounts": [
+        {
+          "name": "player",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "playerProfile",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nicknameRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "previousNicknameRecord",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "nickname",
+          "type": "string"
+        },
+        {
+          "name": "avatarUri",
+          "type": "string"
+        },
+        {
+          "name": "showOnLeaderboard",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "resizePlayerHistory",
+      "accounts": [
+        {
+          "name": "player",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "playerHistory",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "capacity",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "revokeSession",
+      "accounts": [
+        {
+          "name": "player",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "session",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "playerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "GameVault",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "tokenBump",
+            "type": "u8"
+          },
+          {
+            "name": "initialTokens",
+            "type": "u64"
+          },
+          {
+            "name": "mintRemaining",
+            "type": "u64"
+          },
+          {
+            "name": "burnedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "participationFlags",
+            "type": {
+              "array": [
+                "u8",
+                1000
+              ]
+            }
+          },
+          {
+            "name": "currentIndex",
+            "type": "u16"
+          },
+          {
+            "name": "numZeroStakes",
+            "type": "u32"
+          },
+          {
+            "name": "numHundredStakes",
+            "type": "u32"
+          },
+          {
+            "name": "numSplits",
+            "type": "u16"
+          },
+          {
+            "name": "numSteals",
+            "type": "u16"
+          },
+          {
+            "name": "jackpotBump",
+            "type": "u8"
+          },
+          {
+            "name": "jackpotAmount",
+            "type": "u64"
+          },
+          {
+            "name": "jackpotContributed",
+            "type": "u64"
+          },
+          {
+            "name": "jackpotPaidOut",
+            "type": "u64"
+          },
+          {
+            "name": "numJackpots",
+            "type": "u32"
+          },
+          {
+            "name": "config",
+            "type": {
+              "defined": "VaultConfig"
+            }
+          },
+          {
+            "name": "numGames",
+            "type": "u64"
+          },
+          {
+            "name": "season",
+            "type": "u32"
+          },
+          {
+            "name": "seasonStartSlot",
+            "type": "u64"
+          },
+          {
+            "name": "seasonNumGames",
+            "type": "u64"
+          },
+          {
+            "name": "throttle",
+            "type": {
+              "defined": "PlayThrottle"
+            }
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "anomalyStats",
+            "type": {
+              "defined": "AnomalyStats"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "InstructionAllowlist",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "numPrograms",
+            "type": "u8"
+          },
+          {
+            "name": "programs",
+            "type": {
+              "array": [
+                {
+                  "defined": "AllowedProgram"
+                },
+                16
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "Integrator",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "programId",
+            "type": "publicKey"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "revenueTokenAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "revenueShareBps",
+            "type": "u16"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "numGames",
+            "type": "u64"
+          },
+          {
+            "name": "tokensEarned",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PlaySession",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "player",
+            "type": "publicKey"
+          },
+          {
+            "name": "sessionKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "expirySlot",
+            "type": "u64"
+          },
+          {
+            "name": "maxTotalStake",
+            "type": "u64"
+          },
+          {
+            "name": "maxGames",
+            "type": "u32"
+          },
+          {
+            "name": "totalStaked",
+            "type": "u64"
+          },
+          {
+            "name": "numGames",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PlayerHistory",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "player",
+            "type": "publicKey"
+          },
+          {
+            "name": "capacity",
+            "type": "u16"
+          },
+          {
+            "name": "nextIndex",
+            "type": "u16"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": "HistoryEntry"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "PlayerProfile",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "isInitialized",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "lastPlayedSlot",
+            "type": "u64"
+          },
+          {
+            "name": "tokensGained",
+            "type": "u64"
+          },
+          {
+            "name": "tokensBurned",
+            "type": "u64"
+          },
+          {
+            "name": "numGames",
+            "type": "u32"
+          },
+          {
+            "name": "numSplitBonuses",
+            "type": "u32"
+          },
+          {
+            "name": "averageNumSplits",
+            "type": "u64"
+          },
+          {
+            "name": "averageStakeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "referrer",
+            "type": "publicKey"
+          },
+          {
+            "name": "numReferrals",
+            "type": "u32"
+          },
+          {
+            "name": "referralLamportsEarned",
+            "type": "u64"
+          },
+          {
+            "name": "referralTokensEarned",
+            "type": "u64"
+          },
+          {
+            "name": "seasonStats",
+            "type": {
+              "defined": "PlayerSeasonStats"
+            }
+          },
+          {
+            "name": "team",
+            "type": "publicKey"
+          },
+          {
+            "name": "teamChangedSlot",
+            "type": "u64"
+          },
+          {
+            "name": "burnRateCheckFailures",
+            "type": "u32"
+          },
+          {
+            "name": "flaggedSlot",
+            "type": "u64"
+          },
+          {
+            "name": "lockedUntilSlot",
+            "type": "u64"
+          },
+          {
+            "name": "luckExpectedGained",
+            "type": "u64"
+          },
+          {
+            "name": "luckActualGained",
+            "type": "u64"
+          },
+          {
+            "name": "luckVariance",
+            "type": "u128"
+          },
+          {
+            "name": "luckNumGames",
+            "type": "u32"
+          },
+          {
+            "name": "luckSprt",
+            "type": {
+              "defined": "LuckSprt"
+            }
+          },
+          {
+            "name": "depositLamports",
+            "type": "u64"
+          },
+          {
+            "name": "depositor",
+            "type": "publicKey"
+          },
+          {
+            "name": "stats",
+            "type": {
+              "defined": "PlayerStats"
+            }
+          },
+          {
+            "name": "achievements",
+            "type": "u32"
+          },
+          {
+            "name": "claimedAchievements",
+            "type": "u32"
+          },
+          {
+            "name": "primaryWallet",
+            "type": "publicKey"
+          },
+          {
+            "name": "numLinkedWallets",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProfileMetadata",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "player",
+            "type": "publicKey"
+          },
+          {
+            "name": "nickname",
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          },
+          {
+            "name": "avatarUri",
+            "type": {
+              "array": [
+                "u8",
+                128
+              ]
+            }
+          },
+          {
+            "name": "showOnLeaderboard",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "NicknameRecord",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "player",
+            "type": "publicKey"
+          },
+          {
+            "name": "nickname",
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProfileTombstone",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "numCloses",
+            "type": "u32"
+          },
+          {
+            "name": "lastPlayedSlot",
+            "type": "u64"
+          },
+          {
+            "name": "tokensGained",
+            "type": "u64"
+          },
+          {
+            "name": "tokensBurned",
+            "type": "u64"
+          },
+          {
+            "name": "numGames",
+            "type": "u32"
+          },
+          {
+            "name": "numSplitBonuses",
+            "type": "u32"
+          },
+          {
+            "name": "averageNumSplits",
+            "type": "u64"
+          },
+          {
+            "name": "averageStakeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "seasonStats",
+            "type": {
+              "defined": "PlayerSeasonStats"
+            }
+          },
+          {
+            "name": "burnRateCheckFailures",
+            "type": "u32"
+          },
+          {
+            "name": "flaggedSlot",
+            "type": "u64"
+          },
+          {
+            "name": "lockedUntilSlot",
+            "type": "u64"
+          },
+          {
+            "name": "luckExpectedGained",
+            "type": "u64"
+          },
+          {
+            "name": "luckActualGained",
+            "type": "u64"
+          },
+          {
+            "name": "luckVariance",
+            "type": "u128"
+          },
+          {
+            "name": "luckNumGames",
+            "type": "u32"
+          },
+          {
+            "name": "luckSprt",
+            "type": {
+              "defined": "LuckSprt"
+            }
+          },
+          {
+            "name": "stats",
+            "type": {
+              "defined": "PlayerStats"
+            }
+          },
+          {
+            "name": "achievements",
+            "type": "u32"
+          },
+          {
+            "name": "claimedAchievements",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SeasonArchive",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "season",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "startSlot",
+            "type": "u64"
+          },
+          {
+            "name": "endSlot",
+            "type": "u64"
+          },
+          {
+            "name": "numGames",
+            "type": "u64"
+          },
+          {
+            "name": "numSplits",
+            "type": "u16"
+          },
+          {
+            "name": "numSteals",
+            "type": "u16"
+          },
+          {
+            "name": "burnedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "mintRemaining",
+            "type": "u64"
+          },
+          {
+            "name": "numZeroStakes",
+            "type": "u32"
+          },
+          {
+            "name": "numHundredStakes",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Team",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "leader",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "name",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "maxMembers",
+            "type": "u16"
+          },
+          {
+            "name": "numMembers",
+            "type": "u16"
+          },
+          {
+            "name": "numGames",
+            "type": "u64"
+          },
+          {
+            "name": "tokensGained",
+            "type": "u64"
+          },
+          {
+            "name": "tokensBurned",
+            "type": "u64"
+          },
+          {
+            "name": "numSplits",
+            "type": "u64"
+          },
+          {
+            "name": "numSteals",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TeamLeaderboard",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "numEntries",
+            "type": "u8"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "array": [
+                {
+                  "defined": "TeamLeaderboardEntry"
+                },
+                10
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "Treasury",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "totalFeesCollected",
+            "type": "u64"
+          },
+          {
+            "name": "numFees",
+            "type": "u64"
+          },
+          {
+            "name": "totalWithdrawn",
+            "type": "u64"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "BatchGame",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "choice",
+            "type": {
+              "defined": "Choice"
+            }
+          },
+          {
+            "name": "stakeAmount",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "GameResult",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "stakeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "playerChoice",
+            "type": {
+              "defined": "Choice"
+            }
+          },
+          {
+            "name": "opponentChoice",
+            "type": {
+              "defined": "Choice"
+            }
+          },
+          {
+            "name": "outcomeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "burnedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "jackpotContribution",
+            "type": "u64"
+          },
+          {
+            "name": "jackpotPayout",
+            "type": "u64"
+          },
+          {
+            "name": "referralPayout",
+            "type": "u64"
+          },
+          {
+            "name": "integratorPayout",
+            "type": "u64"
+          },
+          {
+            "name": "gotSplitBonus",
+            "type": "bool"
+          },
+          {
+            "name": "passesBurnRateCheck",
+            "type": "bool"
+          },
+          {
+            "name": "passesWinningsCheck",
+            "type": "bool"
+          },
+          {
+            "name": "passesSprtCheck",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AnomalyStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "numGames",
+            "type": "u64"
+          },
+          {
+            "name": "expectedPayout",
+            "type": "u64"
+          },
+          {
+            "name": "actualPayout",
+            "type": "u64"
+          },
+          {
+            "name": "payoutVariance",
+            "type": "u128"
+          },
+          {
+            "name": "expectedBurn",
+            "type": "u64"
+          },
+          {
+            "name": "actualBurn",
+            "type": "u64"
+          },
+          {
+            "name": "burnVariance",
+            "type": "u128"
+          },
+          {
+            "name": "expectedSplitBonuses",
+            "type": "u64"
+          },
+          {
+            "name": "numSplitBonuses",
+            "type": "u64"
+          },
+          {
+            "name": "splitBonusVariance",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyGameVault",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "tokenBump",
+            "type": "u8"
+          },
+          {
+            "name": "initialTokens",
+            "type": "u64"
+          },
+          {
+            "name": "mintRemaining",
+            "type": "u64"
+          },
+          {
+            "name": "burnedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "participationFlags",
+            "type": {
+              "array": [
+                "u8",
+                1000
+              ]
+            }
+          },
+          {
+            "name": "currentIndex",
+            "type": "u16"
+          },
+          {
+            "name": "numZeroStakes",
+            "type": "u32"
+          },
+          {
+            "name": "numHundredStakes",
+            "type": "u32"
+          },
+          {
+            "name": "numSplits",
+            "type": "u16"
+          },
+          {
+            "name": "numSteals",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AllowedProgram",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programId",
+            "type": "publicKey"
+          },
+          {
+            "name": "position",
+            "type": {
+              "defined": "InstructionPosition"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LuckSprt",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "logLikelihoodRatio",
+            "type": "i64"
+          },
+          {
+            "name": "numGames",
+            "type": "u32"
+          },
+          {
+            "name": "numRestarts",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PlayThrottle",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "currentSlot",
+            "type": "u64"
+          },
+          {
+            "name": "playsInSlot",
+            "type": "u32"
+          },
+          {
+            "name": "largeDropsInSlot",
+            "type": "u32"
+          },
+          {
+            "name": "windowStartSlot",
+            "type": "u64"
+          },
+          {
+            "name": "playsInWindow",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "HistoryEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "slot",
+            "type": "u64"
+          },
+          {
+            "name": "stakeAmount",
+            "type": "u16"
+          },
+          {
+            "name": "playerChoice",
+            "type": {
+              "defined": "Choice"
+            }
+          },
+          {
+            "name": "opponentChoice",
+            "type": {
+              "defined": "Choice"
+            }
+          },
+          {
+            "name": "outcomeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "gotSplitBonus",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PlayerSeasonStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "season",
+            "type": "u32"
+          },
+          {
+            "name": "numGames",
+            "type": "u32"
+          },
+          {
+            "name": "tokensGained",
+            "type": "u64"
+          },
+          {
+            "name": "tokensBurned",
+            "type": "u64"
+          },
+          {
+            "name": "numSplitBonuses",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OutcomeCell",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wins",
+            "type": "u32"
+          },
+          {
+            "name": "losses",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PlayerStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "numSplits",
+            "type": "u32"
+          },
+          {
+            "name": "numSteals",
+            "type": "u32"
+          },
+          {
+            "name": "currentSplitStreak",
+            "type": "u32"
+          },
+          {
+            "name": "splitSplit",
+            "type": {
+              "defined": "OutcomeCell"
+            }
+          },
+          {
+            "name": "splitSteal",
+            "type": {
+              "defined": "OutcomeCell"
+            }
+          },
+          {
+            "name": "stealSplit",
+            "type": {
+              "defined": "OutcomeCell"
+            }
+          },
+          {
+            "name": "stealSteal",
+            "type": {
+              "defined": "OutcomeCell"
+            }
+          },
+          {
+            "name": "currentWinStreak",
+            "type": "u32"
+          },
+          {
+            "name": "bestWinStreak",
+            "type": "u32"
+          },
+          {
+            "name": "largestPayout",
+            "type": "u64"
+          },
+          {
+            "name": "largestBurn",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyPlayerProfile",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "isInitialized",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "lastPlayedSlot",
+            "type": "u64"
+          },
+          {
+            "name": "tokensGained",
+            "type": "u64"
+          },
+          {
+            "name": "tokensBurned",
+            "type": "u64"
+          },
+          {
+            "name": "numGames",
+            "type": "u32"
+          },
+          {
+            "name": "numSplitBonuses",
+            "type": "u8"
+          },
+          {
+            "name": "averageNumSplits",
+            "type": "u64"
+          },
+          {
+            "name": "averageStakeAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TeamLeaderboardEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "team",
+            "type": "publicKey"
+          },
+          {
+            "name": "score",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "VaultConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "jackpotBps",
+            "type": "u16"
+          },
+          {
+            "name": "jackpotOdds",
+            "type": "u32"
+          },
+          {
+            "name": "referralFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "referralPayoutBps",
+            "type": "u16"
+          },
+          {
+            "name": "maxPlaysPerSlot",
+            "type": "u32"
+          },
+          {
+            "name": "maxPlaysPerWindow",
+            "type": "u32"
+          },
+          {
+            "name": "throttleWindowSlots",
+            "type": "u64"
+          },
+          {
+            "name": "largeDropAmount",
+            "type": "u64"
+          },
+          {
+            "name": "maxLargeDropsPerSlot",
+            "type": "u32"
+          },
+          {
+            "name": "sprtOddsRatioBps",
+            "type": "u32"
+          },
+          {
+            "name": "sprtAlphaBps",
+            "type": "u16"
+          },
+          {
+            "name": "sprtBetaBps",
+            "type": "u16"
+          },
+          {
+            "name": "splitBonusConfidenceBps",
+            "type": "u16"
+          },
+          {
+            "name": "anomalyPauseZScore",
+            "type": "u64"
+          },
+          {
+            "name": "anomalyMinGames",
+            "type": "u64"
+          },
+          {
+            "name": "profileFeeAsDeposit",
+            "type": "bool"
+          },
+          {
+            "name": "depositRefundMinGames",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Achievement",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "FirstSplitBonus"
+          },
+          {
+            "name": "ThousandGames"
+          },
+          {
+            "name": "TenSplitsInARow"
+          },
+          {
+            "name": "SurvivedStealHeavyVault"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Anomaly",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Payouts"
+          },
+          {
+            "name": "Burns"
+          },
+          {
+            "name": "SplitBonuses"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Choice",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Split"
+          },
+          {
+            "name": "Steal"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InstructionPosition",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Before"
+          },
+          {
+            "name": "After"
+          },
+          {
+            "name": "Either"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "VaultAutoPaused",
+      "fields": [
+        {
+          "name": "anomaly",
+          "type": {
+            "defined": "Anomaly"
+          },
+          "index": false
+        },
+        {
+          "name": "slot",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "stats",
+          "type": {
+            "defined": "AnomalyStats"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PlayerStatsUpdated",
+      "fields": [
+        {
+          "name": "player",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "numGames",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "stats",
+          "type": {
+            "defined": "PlayerStats"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AchievementsEarned",
+      "fields": [
+        {
+          "name": "player",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "achievements",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "BurnRateCheckFailed",
+      "fields": [
+        {
+          "name": "player",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "numFailures",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "flaggedSlot",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "lockedUntilSlot",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "failedBurnRateCheck",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "failedWinningsCheck",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "failedSprtCheck",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "numGames",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "tokensGained",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "tokensBurned",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "InsufficientTokens",
+      "msg": "Not enough tokens for requested stake"
+    },
+    {
+      "code": 6001,
+      "name": "InvalidInitialTokens",
+      "msg": "The initial tokens amount must be exactly 1,000,000,000."
+    },
+    {
+      "code": 6002,
+      "name": "InvalidMintSupply",
+      "msg": "The mint supply must be exactly 1,000,000,000."
+    },
+    {
+      "code": 6003,
+      "name": "InvalidMintDecimals",
+      "msg": "The mint decimals must be exactly 9."
+    },
+    {
+      "code": 6004,
+      "name": "MintAuthorityNotRevoked",
+      "msg": "The mint authority has not been revoked."
+    },
+    {
+      "code": 6005,
+      "name": "FreezeAuthorityNotRevoked",
+      "msg": "The freeze authority has not been revoked."
+    },
+    {
+      "code": 6006,
+      "name": "SlotHashNotFound",
+      "msg": "The slot hash couldn't be found. This should never happen"
+    },
+    {
+      "code": 6007,
+      "name": "StakeAmountTooHigh",
+      "msg": "Can only stake between 0 and 100"
+    },
+    {
+      "code": 6008,
+      "name": "InvalidComputeBudgetProgramId",
+      "msg": "Only other program allowed is Compute Budget"
+    },
+    {
+      "code": 6009,
+      "name": "UnexpectedInstruction",
+      "msg": "No instructions after the play_game instruction are allowed"
+    },
+    {
+      "code": 6010,
+      "name": "InvalidActiveProgram",
+      "msg": "Invalid active program."
+    },
+    {
+      "code": 6011,
+      "name": "MintOver",
+      "msg": "Mint finished. All 1,000,000,000 tokens transfered out of the account"
+    },
+    {
+      "code": 6012,
+      "name": "PlayedAgainTooSoon",
+      "msg": "Submitting transaction too close together, only allowed once per 3 slots"
+    },
+    {
+      "code": 6013,
+      "name": "EmptyBatch",
+      "msg": "A batch needs at least one game"
+    },
+    {
+      "code": 6014,
+      "name": "BatchTooLarge",
+      "msg": "Too many games in one batch"
+    },
+    {
+      "code": 6015,
+      "name": "InvalidBasisPoints",
+      "msg": "Basis points can be at most 10,000"
+    },
+    {
+      "code": 6016,
+      "name": "JackpotAccountMissing",
+      "msg": "The jackpot token account is required while the jackpot is enabled"
+    },
+    {
+      "code": 6017,
+      "name": "InvalidReferrer",
+      "msg": "The referrer must be an existing player other than yourself"
+    },
+    {
+      "code": 6018,
+      "name": "ReferrerAccountMissing",
+      "msg": "The referrer's profile and token account are required to pay them"
+    },
+    {
+      "code": 6019,
+      "name": "InvalidTeamName",
+      "msg": "Team names must be between 1 and 32 bytes"
+    },
+    {
+      "code": 6020,
+      "name": "InvalidTeamSize",
+      "msg": "Teams can have between 1 and 50 members"
+    },
+    {
+      "code": 6021,
+      "name": "TeamFull",
+      "msg": "The team is full"
+    },
+    {
+      "code": 6022,
+      "name": "AlreadyInTeam",
+      "msg": "Already in a team"
+    },
+    {
+      "code": 6023,
+      "name": "NotInTeam",
+      "msg": "Not a member of this team"
+    },
+    {
+      "code": 6024,
+      "name": "TeamChangeTooSoon",
+      "msg": "Changed team too recently"
+    },
+    {
+      "code": 6025,
+      "name": "TeamAccountMissing",
+      "msg": "The player's team account is required"
+    },
+    {
+      "code": 6026,
+      "name": "ProfileNotInitialized",
+      "msg": "The player profile must be initialized first"
+    },
+    {
+      "code": 6027,
+      "name": "TooManyAllowedPrograms",
+      "msg": "Too many programs in the instruction allowlist"
+    },
+    {
+      "code": 6028,
+      "name": "CannotAllowGameProgram",
+      "msg": "The game program can't be in its own allowlist"
+    },
+    {
+      "code": 6029,
+      "name": "MultipleGameInstructions",
+      "msg": "Only one game instruction is allowed per transaction"
+    },
+    {
+      "code": 6030,
+      "name": "InstructionNotAllowedBefore",
+      "msg": "Program not allowed before the game instruction"
+    },
+    {
+      "code": 6031,
+      "name": "InstructionNotAllowedAfter",
+      "msg": "Program not allowed after the game instruction"
+    },
+    {
+      "code": 6032,
+      "name": "PlayerNotAuthorized",
+      "msg": "The player must sign, or a valid session key must sign for them"
+    },
+    {
+      "code": 6033,
+      "name": "InvalidSessionKey",
+      "msg": "The session key doesn't match the session"
+    },
+    {
+      "code": 6034,
+      "name": "SessionExpired",
+      "msg": "The session has expired"
+    },
+    {
+      "code": 6035,
+      "name": "SessionGameLimitReached",
+      "msg": "The session has played its maximum number of games"
+    },
+    {
+      "code": 6036,
+      "name": "SessionStakeLimitReached",
+      "msg": "The session has staked its maximum amount"
+    },
+    {
+      "code": 6037,
+      "name": "SlotPlayLimitReached",
+      "msg": "Too many plays across the vault this slot"
+    },
+    {
+      "code": 6038,
+      "name": "WindowPlayLimitReached",
+      "msg": "Too many plays across the vault in the recent slot window"
+    },
+    {
+      "code": 6039,
+      "name": "LargeDropLimitReached",
+      "msg": "Too many large payouts from the vault this slot"
+    },
+    {
+      "code": 6040,
+      "name": "BurnRateLockout",
+      "msg": "Locked out after failing the burn rate check"
+    },
+    {
+      "code": 6041,
+      "name": "InvalidSprtConfig",
+      "msg": "SPRT needs an odds ratio above 1 and error rates between 0 and 1"
+    },
+    {
+      "code": 6042,
+      "name": "VaultPaused",
+      "msg": "The vault is paused"
+    },
+    {
+      "code": 6043,
+      "name": "UnsanctionedCpi",
+      "msg": "Only enabled integrators can call the game through CPI, using play_game_cpi"
+    },
+    {
+      "code": 6044,
+      "name": "NotCalledViaCpi",
+      "msg": "play_game_cpi has to be called through CPI from the integrator program"
+    },
+    {
+      "code": 6045,
+      "name": "IntegratorAccountMissing",
+      "msg": "The integrator, integrator authority and integrator token account are required"
+    },
+    {
+      "code": 6046,
+      "name": "IntegratorDisabled",
+      "msg": "The integrator is disabled"
+    },
+    {
+      "code": 6047,
+      "name": "InvalidIntegratorAuthority",
+      "msg": "The integrator authority or token account doesn't match the integrator"
+    },
+    {
+      "code": 6048,
+      "name": "InsufficientTreasuryBalance",
+      "msg": "Not enough lamports in the treasury above the rent exemption minimum"
+    },
+    {
+      "code": 6049,
+      "name": "DepositNotClaimable",
+      "msg": "The deposit can't be claimed yet"
+    },
+    {
+      "code": 6050,
+      "name": "InvalidDepositor",
+      "msg": "The deposit is refunded to whoever paid it"
+    },
+    {
+      "code": 6051,
+      "name": "StillInTeam",
+      "msg": "Leave the team before closing the profile"
+    },
+    {
+      "code": 6052,
+      "name": "DepositNotClaimed",
+      "msg": "Claim the deposit before closing the profile"
+    },
+    {
+      "code": 6053,
+      "name": "HistoryTooLong",
+      "msg": "Player history is longer than the maximum"
+    },
+    {
+      "code": 6054,
+      "name": "AchievementNotEarned",
+      "msg": "The achievement hasn't been earned"
+    },
+    {
+      "code": 6055,
+      "name": "AchievementAlreadyClaimed",
+      "msg": "The achievement has already been claimed"
+    },
+    {
+      "code": 6056,
+      "name": "InvalidNickname",
+      "msg": "Nicknames must be 3 to 20 ascii letters, digits, underscores or dashes"
+    },
+    {
+      "code": 6057,
+      "name": "InvalidAvatarUri",
+      "msg": "Avatar URIs must be https, ipfs or ar links of at most 128 bytes"
+    },
+    {
+      "code": 6058,
+      "name": "NicknameTaken",
+      "msg": "The nickname is taken"
+    },
+    {
+      "code": 6059,
+      "name": "PreviousNicknameRecordMissing",
+      "msg": "The previous nickname record is required when changing nickname"
+    },
+    {
+      "code": 6060,
+      "name": "InvalidPreviousNicknameRecord",
+      "msg": "The previous nickname record doesn't match the current nickname"
+    },
+    {
+      "code": 6061,
+      "name": "LinkedProfileMissing",
+      "msg": "The wallet is linked, pass the primary wallet's profile as the linked profile"
+    },
+    {
+      "code": 6062,
+      "name": "InvalidLinkedProfile",
+      "msg": "The linked profile doesn't match the one the wallet is linked to"
+    },
+    {
+      "code": 6063,
+      "name": "ProfileLinked",
+      "msg": "Linked profiles can't be closed"
+    },
+    {
+      "code": 6064,
+      "name": "InvalidWalletLink",
+      "msg": "Only an unlinked wallet without linked wallets of its own can be linked to an unlinked primary"
+    },
+    {
+      "code": 6065,
+      "name": "LegacyProfileNotMigrated",
+      "msg": "Move the legacy profile over with migrate_profile first"
+    },
+    {
+      "code": 6066,
+      "name": "VaultAlreadyMigrated",
+      "msg": "The vault is already in the current layout"
+    },
+    {
+      "code": 6067,
+      "name": "InvalidLegacyVault",
+      "msg": "The vault isn't a legacy vault of this creator"
+    },
+    {
+      "code": 6068,
+      "name": "JackpotNotInitialized",
+      "msg": "Initialize the jackpot before enabling it"
+    },
+    {
+      "code": 6069,
+      "name": "ProgramNotPassive",
+      "msg": "Only passive programs like memo can be allowed after the game instruction"
+    },
+    {
+      "code": 6070,
+      "name": "InvalidSessionStake",
+      "msg": "The session's max total stake is too large"
+    },
+    {
+      "code": 6071,
+      "name": "InvalidDepositConfig",
+      "msg": "The deposit refund needs at least enough games to pass the significance test"
+    },
+    {
+      "code": 6072,
+      "name": "HasReferrals",
+      "msg": "Profiles that referred players can't be closed"
+    },
+    {
+      "code": 6073,
+      "name": "InvalidLegacyProfile",
+      "msg": "The legacy profile isn't in the originally deployed layout"
+    },
+    {
+      "code": 6074,
+      "name": "InvalidJackpotConfig",
+      "msg": "The jackpot needs both a share of lost stakes and odds, or neither"
+    },
+    {
+      "code": 6075,
+      "name": "WalletNotLinked",
+      "msg": "Only wallets linked to a primary wallet pass a linked profile"
+    }
+  ]
+};
+
+export const IDL: SplitOrSteal = {
+  "version": "0.1.0",
+  "name": "split_or_steal",
+  "instructions": [
+    {
+      "name": "initializeVault",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "creatorTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gameVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gameVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "tokenAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "playGame",
+      "accounts": [
+        {
+          "name": "player",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "playerProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileTombstone",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "legacyProfile",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "playerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gameVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "slotHashes",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "jackpotTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "referrerProfile",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "referrerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "team",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "instructionAllowlist",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "sessionKey",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true
+        },
+        {
+          "name": "session",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "integrator",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "integratorAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true
+        },
+        {
+          "name": "integratorTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "playerHistory",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "linkedProfile",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "choice",
+          "type": {
+            "defined": "Choice"
+          }
+        },
+        {
+          "name": "stakeAmount",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "playBatch",
+      "accounts": [
+        {
+          "name": "player",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "playerProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileTombstone",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "legacyProfile",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "playerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gameVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "slotHashes",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "jackpotTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "referrerProfile",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "referrerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "team",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "instructionAllowlist",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "sessionKey",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true
+        },
+        {
+          "name": "session",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "integrator",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "integratorAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true
+        },
+        {
+          "name": "integratorTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "playerHistory",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "linkedProfile",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "games",
+          "type": {
+            "vec": {
+              "defined": "BatchGame"
+            }
+          }
+        }
+      ],
+      "returns": {
+        "vec": {
+          "defined": "GameResult"
+        }
+      }
+    },
+    {
+      "name": "playGameCpi",
+      "accounts": [
+        {
+          "name": "player",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "playerProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileTombstone",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "legacyProfile",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "playerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gameVaultTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "slotHashes",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "jackpotTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "referrerProfile",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "referrerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "team",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "instructionAllowlist",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "sessionKey",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true
+        },
+        {
+          "name": "session",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "integrator",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "integratorAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true
+        },
+        {
+          "name": "integratorTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "playerHistory",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "linkedProfile",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "choice",
+          "type": {
+            "defined": "Choice"
+          }
+        },
+        {
+          "name": "stakeAmount",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "updateConfig",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "config",
+          "type": {
+            "defined": "VaultConfig"
+          }
+        }
+      ]
+    },
+    {
+      "name": "migrateVault",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setIntegrator",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "integrator",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "programId",
+          "type": "publicKey"
+        },
+        {
+          "name": "revenueTokenAccount",
+          "type": "publicKey"
+        },
+        {
+          "name": "revenueShareBps",
+          "type": "u16"
+        },
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setVaultPaused",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "bool"
+        },
+        {
+          "name": "resetAnomalyStats",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "initializeJackpot",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gameVaultTokenAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "jackpotTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeTreasury",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawTreasury",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "startSeason",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "seasonArchive",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createTeam",
+      "accounts": [
+        {
+          "name": "leader",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "leaderProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "team",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "maxMembers",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "joinTeam",
+      "accounts": [
+        {
+          "name": "player",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "playerProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "team",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "leaveTeam",
+      "accounts": [
+        {
+          "name": "player",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "playerProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "team",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateProfile",
+      "accounts": [
+        {
+          "name": "player",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "legacyProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "playerProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "linkWallet",
+      "accounts": [
+        {
+          "name": "primary",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "primaryProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "secondary",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "secondaryProfile",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeTeamLeaderboard",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "teamLeaderboard",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateTeamLeaderboard",
+      "accounts": [
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "teamLeaderboard",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "team",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setInstructionAllowlist",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructionAllowlist",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "programs",
+          "type": {
+            "vec": {
+              "defined": "AllowedProgram"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeAchievementCollection",
+      "accounts": [
+        {
+          "name": "creator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "achievementCollection",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimAchievement",
+      "accounts": [
+        {
+          "name": "player",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "playerProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "achievementMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "achievementCollection",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "achievementTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "achievement",
+          "type": {
+            "defined": "Achievement"
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimDeposit",
+      "accounts": [
+        {
+          "name": "player",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "playerProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "depositor",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeProfile",
+      "accounts": [
+        {
+          "name": "player",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "playerProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileTombstone",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createSession",
+      "accounts": [
+        {
+          "name": "player",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "session",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "playerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "sessionKey",
+          "type": "publicKey"
+        },
+        {
+          "name": "expirySlot",
+          "type": "u64"
+        },
+        {
+          "name": "maxTotalStake",
+          "type": "u64"
+        },
+        {
+          "name": "maxGames",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "setProfileMetadata",
+      "accounts": [
+        {
+          "name": "player",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "playerProfile",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nicknameRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "previousNicknameRecord",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "nickname",
+          "type": "string"
+        },
+        {
+          "name": "avatarUri",
+          "type": "string"
+        },
+        {
+          "name": "showOnLeaderboard",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "resizePlayerHistory",
+      "accounts": [
+        {
+          "name": "player",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gameVault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "playerHistory",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "capacity",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "revokeSession",
+      "accounts": [
+        {
+          "name": "player",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "session",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "playerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "GameVault",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "tokenBump",
+            "type": "u8"
+          },
+          {
+            "name": "initialTokens",
+            "type": "u64"
+          },
+          {
+            "name": "mintRemaining",
+            "type": "u64"
+          },
+          {
+            "name": "burnedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "participationFlags",
+            "type": {
+              "array": [
+                "u8",
+                1000
+              ]
+            }
+          },
+          {
+            "name": "currentIndex",
+            "type": "u16"
+          },
+          {
+            "name": "numZeroStakes",
+            "type": "u32"
+          },
+          {
+            "name": "numHundredStakes",
+            "type": "u32"
+          },
+          {
+            "name": "numSplits",
+            "type": "u16"
+          },
+          {
+            "name": "numSteals",
+            "type": "u16"
+          },
+          {
+            "name": "jackpotBump",
+            "type": "u8"
+          },
+          {
+            "name": "jackpotAmount",
+            "type": "u64"
+          },
+          {
+            "name": "jackpotContributed",
+            "type": "u64"
+          },
+          {
+            "name": "jackpotPaidOut",
+            "type": "u64"
+          },
+          {
+            "name": "numJackpots",
+            "type": "u32"
+          },
+          {
+            "name": "config",
+            "type": {
+              "defined": "VaultConfig"
+            }
+          },
+          {
+            "name": "numGames",
+            "type": "u64"
+          },
+          {
+            "name": "season",
+            "type": "u32"
+          },
+          {
+            "name": "seasonStartSlot",
+            "type": "u64"
+          },
+          {
+            "name": "seasonNumGames",
+            "type": "u64"
+          },
+          {
+            "name": "throttle",
+            "type": {
+              "defined": "PlayThrottle"
+            }
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "anomalyStats",
+            "type": {
+              "defined": "AnomalyStats"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "InstructionAllowlist",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "numPrograms",
+            "type": "u8"
+          },
+          {
+            "name": "programs",
+            "type": {
+              "array": [
+                {
+                  "defined": "AllowedProgram"
+                },
+                16
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "Integrator",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "programId",
+            "type": "publicKey"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "revenueTokenAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "revenueShareBps",
+            "type": "u16"
+          },
+          {
+            "name": "enabled",
+            "type": "bool"
+          },
+          {
+            "name": "numGames",
+            "type": "u64"
+          },
+          {
+            "name": "tokensEarned",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PlaySession",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "player",
+            "type": "publicKey"
+          },
+          {
+            "name": "sessionKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "expirySlot",
+            "type": "u64"
+          },
+          {
+            "name": "maxTotalStake",
+            "type": "u64"
+          },
+          {
+            "name": "maxGames",
+            "type": "u32"
+          },
+          {
+            "name": "totalStaked",
+            "type": "u64"
+          },
+          {
+            "name": "numGames",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PlayerHistory",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "player",
+            "type": "publicKey"
+          },
+          {
+            "name": "capacity",
+            "type": "u16"
+          },
+          {
+            "name": "nextIndex",
+            "type": "u16"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": "HistoryEntry"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "PlayerProfile",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "isInitialized",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "lastPlayedSlot",
+            "type": "u64"
+          },
+          {
+            "name": "tokensGained",
+            "type": "u64"
+          },
+          {
+            "name": "tokensBurned",
+            "type": "u64"
+          },
+          {
+            "name": "numGames",
+            "type": "u32"
+          },
+          {
+            "name": "numSplitBonuses",
+            "type": "u32"
+          },
+          {
+            "name": "averageNumSplits",
+            "type": "u64"
+          },
+          {
+            "name": "averageStakeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "referrer",
+            "type": "publicKey"
+          },
+          {
+            "name": "numReferrals",
+            "type": "u32"
+          },
+          {
+            "name": "referralLamportsEarned",
+            "type": "u64"
+          },
+          {
+            "name": "referralTokensEarned",
+            "type": "u64"
+          },
+          {
+            "name": "seasonStats",
+            "type": {
+              "defined": "PlayerSeasonStats"
+            }
+          },
+          {
+            "name": "team",
+            "type": "publicKey"
+          },
+          {
+            "name": "teamChangedSlot",
+            "type": "u64"
+          },
+          {
+            "name": "burnRateCheckFailures",
+            "type": "u32"
+          },
+          {
+            "name": "flaggedSlot",
+            "type": "u64"
+          },
+          {
+            "name": "lockedUntilSlot",
+            "type": "u64"
+          },
+          {
+            "name": "luckExpectedGained",
+            "type": "u64"
+          },
+          {
+            "name": "luckActualGained",
+            "type": "u64"
+          },
+          {
+            "name": "luckVariance",
+            "type": "u128"
+          },
+          {
+            "name": "luckNumGames",
+            "type": "u32"
+          },
+          {
+            "name": "luckSprt",
+            "type": {
+              "defined": "LuckSprt"
+            }
+          },
+          {
+            "name": "depositLamports",
+            "type": "u64"
+          },
+          {
+            "name": "depositor",
+            "type": "publicKey"
+          },
+          {
+            "name": "stats",
+            "type": {
+              "defined": "PlayerStats"
+            }
+          },
+          {
+            "name": "achievements",
+            "type": "u32"
+          },
+          {
+            "name": "claimedAchievements",
+            "type": "u32"
+          },
+          {
+            "name": "primaryWallet",
+            "type": "publicKey"
+          },
+          {
+            "name": "numLinkedWallets",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProfileMetadata",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "player",
+            "type": "publicKey"
+          },
+          {
+            "name": "nickname",
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          },
+          {
+            "name": "avatarUri",
+            "type": {
+              "array": [
+                "u8",
+                128
+              ]
+            }
+          },
+          {
+            "name": "showOnLeaderboard",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "NicknameRecord",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "player",
+            "type": "publicKey"
+          },
+          {
+            "name": "nickname",
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProfileTombstone",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "numCloses",
+            "type": "u32"
+          },
+          {
+            "name": "lastPlayedSlot",
+            "type": "u64"
+          },
+          {
+            "name": "tokensGained",
+            "type": "u64"
+          },
+          {
+            "name": "tokensBurned",
+            "type": "u64"
+          },
+          {
+            "name": "numGames",
+            "type": "u32"
+          },
+          {
+            "name": "numSplitBonuses",
+            "type": "u32"
+          },
+          {
+            "name": "averageNumSplits",
+            "type": "u64"
+          },
+          {
+            "name": "averageStakeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "seasonStats",
+            "type": {
+              "defined": "PlayerSeasonStats"
+            }
+          },
+          {
+            "name": "burnRateCheckFailures",
+            "type": "u32"
+          },
+          {
+            "name": "flaggedSlot",
+            "type": "u64"
+          },
+          {
+            "name": "lockedUntilSlot",
+            "type": "u64"
+          },
+          {
+            "name": "luckExpectedGained",
+            "type": "u64"
+          },
+          {
+            "name": "luckActualGained",
+            "type": "u64"
+          },
+          {
+            "name": "luckVariance",
+            "type": "u128"
+          },
+          {
+            "name": "luckNumGames",
+            "type": "u32"
+          },
+          {
+            "name": "luckSprt",
+            "type": {
+              "defined": "LuckSprt"
+            }
+          },
+          {
+            "name": "stats",
+            "type": {
+              "defined": "PlayerStats"
+            }
+          },
+          {
+            "name": "achievements",
+            "type": "u32"
+          },
+          {
+            "name": "claimedAchievements",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SeasonArchive",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "season",
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "startSlot",
+            "type": "u64"
+          },
+          {
+            "name": "endSlot",
+            "type": "u64"
+          },
+          {
+            "name": "numGames",
+            "type": "u64"
+          },
+          {
+            "name": "numSplits",
+            "type": "u16"
+          },
+          {
+            "name": "numSteals",
+            "type": "u16"
+          },
+          {
+            "name": "burnedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "mintRemaining",
+            "type": "u64"
+          },
+          {
+            "name": "numZeroStakes",
+            "type": "u32"
+          },
+          {
+            "name": "numHundredStakes",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Team",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "leader",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "name",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "maxMembers",
+            "type": "u16"
+          },
+          {
+            "name": "numMembers",
+            "type": "u16"
+          },
+          {
+            "name": "numGames",
+            "type": "u64"
+          },
+          {
+            "name": "tokensGained",
+            "type": "u64"
+          },
+          {
+            "name": "tokensBurned",
+            "type": "u64"
+          },
+          {
+            "name": "numSplits",
+            "type": "u64"
+          },
+          {
+            "name": "numSteals",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TeamLeaderboard",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "numEntries",
+            "type": "u8"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "array": [
+                {
+                  "defined": "TeamLeaderboardEntry"
+                },
+                10
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "Treasury",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "totalFeesCollected",
+            "type": "u64"
+          },
+          {
+            "name": "numFees",
+            "type": "u64"
+          },
+          {
+            "name": "totalWithdrawn",
+            "type": "u64"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "BatchGame",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "choice",
+            "type": {
+              "defined": "Choice"
+            }
+          },
+          {
+            "name": "stakeAmount",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "GameResult",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "stakeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "playerChoice",
+            "type": {
+              "defined": "Choice"
+            }
+          },
+          {
+            "name": "opponentChoice",
+            "type": {
+              "defined": "Choice"
+            }
+          },
+          {
+            "name": "outcomeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "burnedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "jackpotContribution",
+            "type": "u64"
+          },
+          {
+            "name": "jackpotPayout",
+            "type": "u64"
+          },
+          {
+            "name": "referralPayout",
+            "type": "u64"
+          },
+          {
+            "name": "integratorPayout",
+            "type": "u64"
+          },
+          {
+            "name": "gotSplitBonus",
+            "type": "bool"
+          },
+          {
+            "name": "passesBurnRateCheck",
+            "type": "bool"
+          },
+          {
+            "name": "passesWinningsCheck",
+            "type": "bool"
+          },
+          {
+            "name": "passesSprtCheck",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AnomalyStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "numGames",
+            "type": "u64"
+          },
+          {
+            "name": "expectedPayout",
+            "type": "u64"
+          },
+          {
+            "name": "actualPayout",
+            "type": "u64"
+          },
+          {
+            "name": "payoutVariance",
+            "type": "u128"
+          },
+          {
+            "name": "expectedBurn",
+            "type": "u64"
+          },
+          {
+            "name": "actualBurn",
+            "type": "u64"
+          },
+          {
+            "name": "burnVariance",
+            "type": "u128"
+          },
+          {
+            "name": "expectedSplitBonuses",
+            "type": "u64"
+          },
+          {
+            "name": "numSplitBonuses",
+            "type": "u64"
+          },
+          {
+            "name": "splitBonusVariance",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyGameVault",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "tokenBump",
+            "type": "u8"
+          },
+          {
+            "name": "initialTokens",
+            "type": "u64"
+          },
+          {
+            "name": "mintRemaining",
+            "type": "u64"
+          },
+          {
+            "name": "burnedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "participationFlags",
+            "type": {
+              "array": [
+                "u8",
+                1000
+              ]
+            }
+          },
+          {
+            "name": "currentIndex",
+            "type": "u16"
+          },
+          {
+            "name": "numZeroStakes",
+            "type": "u32"
+          },
+          {
+            "name": "numHundredStakes",
+            "type": "u32"
+          },
+          {
+            "name": "numSplits",
+            "type": "u16"
+          },
+          {
+            "name": "numSteals",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AllowedProgram",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programId",
+            "type": "publicKey"
+          },
+          {
+            "name": "position",
+            "type": {
+              "defined": "InstructionPosition"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LuckSprt",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "logLikelihoodRatio",
+            "type": "i64"
+          },
+          {
+            "name": "numGames",
+            "type": "u32"
+          },
+          {
+            "name": "numRestarts",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PlayThrottle",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "currentSlot",
+            "type": "u64"
+          },
+          {
+            "name": "playsInSlot",
+            "type": "u32"
+          },
+          {
+            "name": "largeDropsInSlot",
+            "type": "u32"
+          },
+          {
+            "name": "windowStartSlot",
+            "type": "u64"
+          },
+          {
+            "name": "playsInWindow",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "HistoryEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "slot",
+            "type": "u64"
+          },
+          {
+            "name": "stakeAmount",
+            "type": "u16"
+          },
+          {
+            "name": "playerChoice",
+            "type": {
+              "defined": "Choice"
+            }
+          },
+          {
+            "name": "opponentChoice",
+            "type": {
+              "defined": "Choice"
+            }
+          },
+          {
+            "name": "outcomeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "gotSplitBonus",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PlayerSeasonStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "season",
+            "type": "u32"
+          },
+          {
+            "name": "numGames",
+            "type": "u32"
+          },
+          {
+            "name": "tokensGained",
+            "type": "u64"
+          },
+          {
+            "name": "tokensBurned",
+            "type": "u64"
+          },
+          {
+            "name": "numSplitBonuses",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OutcomeCell",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "wins",
+            "type": "u32"
+          },
+          {
+            "name": "losses",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PlayerStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "numSplits",
+            "type": "u32"
+          },
+          {
+            "name": "numSteals",
+            "type": "u32"
+          },
+          {
+            "name": "currentSplitStreak",
+            "type": "u32"
+          },
+          {
+            "name": "splitSplit",
+            "type": {
+              "defined": "OutcomeCell"
+            }
+          },
+          {
+            "name": "splitSteal",
+            "type": {
+              "defined": "OutcomeCell"
+            }
           },
           {
-            "name": "participationFlags",
+            "name": "stealSplit",
             "type": {
-              "array": [
-                "u8",
-                1000
-              ]
+              "defined": "OutcomeCell"
             }
           },
           {
-            "name": "currentIndex",
-            "type": "u16"
+            "name": "stealSteal",
+            "type": {
+              "defined": "OutcomeCell"
+            }
           },
           {
-            "name": "numZeroStakes",
+            "name": "currentWinStreak",
             "type": "u32"
           },
           {
-            "name": "numHundredStakes",
+            "name": "bestWinStreak",
             "type": "u32"
           },
           {
-            "name": "numSplits",
-            "type": "u16"
+            "name": "largestPayout",
+            "type": "u64"
           },
           {
-            "name": "numSteals",
-            "type": "u16"
+            "name": "largestBurn",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "playerProfile",
+      "name": "LegacyPlayerProfile",
       "type": {
         "kind": "struct",
         "fields": [
@@ -231,329 +5688,136 @@ export type SplitOrSteal = {
           }
         ]
       }
-    }
-  ],
-  "types": [
+    },
     {
-      "name": "Choice",
+      "name": "TeamLeaderboardEntry",
       "type": {
-        "kind": "enum",
-        "variants": [
+        "kind": "struct",
+        "fields": [
           {
-            "name": "Split"
+            "name": "team",
+            "type": "publicKey"
           },
           {
-            "name": "Steal"
+            "name": "score",
+            "type": "i64"
           }
         ]
       }
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "InsufficientTokens",
-      "msg": "Not enough tokens for requested stake"
-    },
-    {
-      "code": 6001,
-      "name": "InvalidInitialTokens",
-      "msg": "The initial tokens amount must be exactly 1,000,000,000."
-    },
-    {
-      "code": 6002,
-      "name": "InvalidMintSupply",
-      "msg": "The mint supply must be exactly 1,000,000,000."
-    },
-    {
-      "code": 6003,
-      "name": "InvalidMintDecimals",
-      "msg": "The mint decimals must be exactly 9."
-    },
-    {
-      "code": 6004,
-      "name": "MintAuthorityNotRevoked",
-      "msg": "The mint authority has not been revoked."
-    },
-    {
-      "code": 6005,
-      "name": "FreezeAuthorityNotRevoked",
-      "msg": "The freeze authority has not been revoked."
-    },
-    {
-      "code": 6006,
-      "name": "SlotHashNotFound",
-      "msg": "The slot hash couldn't be found. This should never happen"
-    },
-    {
-      "code": 6007,
-      "name": "StakeAmountTooHigh",
-      "msg": "Can only stake between 0 and 100"
-    },
-    {
-      "code": 6008,
-      "name": "InvalidComputeBudgetProgramId",
-      "msg": "Only other program allowed is Compute Budget"
-    },
-    {
-      "code": 6009,
-      "name": "UnexpectedInstruction",
-      "msg": "No instructions after the play_game instruction are allowed"
-    },
-    {
-      "code": 6010,
-      "name": "InvalidActiveProgram",
-      "msg": "Invalid active program."
-    },
-    {
-      "code": 6011,
-      "name": "MintOver",
-      "msg": "Mint finished. All 1,000,000,000 tokens transfered out of the account"
-    },
-    {
-      "code": 6012,
-      "name": "PlayedAgainTooSoon",
-      "msg": "Submitting transaction too close together, only allowed once per 3 slots"
-    }
-  ]
-};
-
-export const IDL: SplitOrSteal = {
-  "version": "0.1.0",
-  "name": "split_or_steal",
-  "instructions": [
-    {
-      "name": "initializeVault",
-      "accounts": [
-        {
-          "name": "creator",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "creatorTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "gameVault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "gameVaultTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "mint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "tokenAmount",
-          "type": "u64"
-        }
-      ]
     },
     {
-      "name": "playGame",
-      "accounts": [
-        {
-          "name": "player",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "playerProfile",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "playerTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "gameVault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "gameVaultTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "creator",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "mint",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "slotHashes",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "instructions",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "choice",
-          "type": {
-            "defined": "Choice"
-          }
-        },
-        {
-          "name": "stakeAmount",
-          "type": "u16"
-        }
-      ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "gameVault",
+      "name": "VaultConfig",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "creator",
-            "type": "publicKey"
+            "name": "jackpotBps",
+            "type": "u16"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "jackpotOdds",
+            "type": "u32"
           },
           {
-            "name": "tokenBump",
-            "type": "u8"
+            "name": "referralFeeBps",
+            "type": "u16"
           },
           {
-            "name": "initialTokens",
-            "type": "u64"
+            "name": "referralPayoutBps",
+            "type": "u16"
           },
           {
-            "name": "mintRemaining",
-            "type": "u64"
+            "name": "maxPlaysPerSlot",
+            "type": "u32"
           },
           {
-            "name": "burnedAmount",
-            "type": "u64"
+            "name": "maxPlaysPerWindow",
+            "type": "u32"
           },
           {
-            "name": "participationFlags",
-            "type": {
-              "array": [
-                "u8",
-                1000
-              ]
-            }
+            "name": "throttleWindowSlots",
+            "type": "u64"
           },
           {
-            "name": "currentIndex",
-            "type": "u16"
+            "name": "largeDropAmount",
+            "type": "u64"
           },
           {
-            "name": "numZeroStakes",
+            "name": "maxLargeDropsPerSlot",
             "type": "u32"
           },
           {
-            "name": "numHundredStakes",
+            "name": "sprtOddsRatioBps",
             "type": "u32"
           },
           {
-            "name": "numSplits",
+            "name": "sprtAlphaBps",
             "type": "u16"
           },
           {
-            "name": "numSteals",
+            "name": "sprtBetaBps",
             "type": "u16"
-          }
-        ]
-      }
-    },
-    {
-      "name": "playerProfile",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "isInitialized",
-            "type": "bool"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "splitBonusConfidenceBps",
+            "type": "u16"
           },
           {
-            "name": "lastPlayedSlot",
+            "name": "anomalyPauseZScore",
             "type": "u64"
           },
           {
-            "name": "tokensGained",
+            "name": "anomalyMinGames",
             "type": "u64"
           },
           {
-            "name": "tokensBurned",
-            "type": "u64"
+            "name": "profileFeeAsDeposit",
+            "type": "bool"
           },
           {
-            "name": "numGames",
+            "name": "depositRefundMinGames",
             "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Achievement",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "FirstSplitBonus"
           },
           {
-            "name": "numSplitBonuses",
-            "type": "u8"
+            "name": "ThousandGames"
           },
           {
-            "name": "averageNumSplits",
-            "type": "u64"
+            "name": "TenSplitsInARow"
           },
           {
-            "name": "averageStakeAmount",
-            "type": "u64"
+            "name": "SurvivedStealHeavyVault"
           }
         ]
       }
-    }
-  ],
-  "types": [
+    },
+    {
+      "name": "Anomaly",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Payouts"
+          },
+          {
+            "name": "Burns"
+          },
+          {
+            "name": "SplitBonuses"
+          }
+        ]
+      }
+    },
     {
       "name": "Choice",
       "type": {
@@ -567,6 +5831,141 @@ export const IDL: SplitOrSteal = {
           }
         ]
       }
+    },
+    {
+      "name": "InstructionPosition",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Before"
+          },
+          {
+            "name": "After"
+          },
+          {
+            "name": "Either"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "VaultAutoPaused",
+      "fields": [
+        {
+          "name": "anomaly",
+          "type": {
+            "defined": "Anomaly"
+          },
+          "index": false
+        },
+        {
+          "name": "slot",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "stats",
+          "type": {
+            "defined": "AnomalyStats"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PlayerStatsUpdated",
+      "fields": [
+        {
+          "name": "player",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "numGames",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "stats",
+          "type": {
+            "defined": "PlayerStats"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AchievementsEarned",
+      "fields": [
+        {
+          "name": "player",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "achievements",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "BurnRateCheckFailed",
+      "fields": [
+        {
+          "name": "player",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "numFailures",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "flaggedSlot",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "lockedUntilSlot",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "failedBurnRateCheck",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "failedWinningsCheck",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "failedSprtCheck",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "numGames",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "tokensGained",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "tokensBurned",
+          "type": "u64",
+          "index": false
+        }
+      ]
     }
   ],
   "errors": [
@@ -634,6 +6033,321 @@ export const IDL: SplitOrSteal = {
       "code": 6012,
       "name": "PlayedAgainTooSoon",
       "msg": "Submitting transaction too close together, only allowed once per 3 slots"
+    },
+    {
+      "code": 6013,
+      "name": "EmptyBatch",
+      "msg": "A batch needs at least one game"
+    },
+    {
+      "code": 6014,
+      "name": "BatchTooLarge",
+      "msg": "Too many games in one batch"
+    },
+    {
+      "code": 6015,
+      "name": "InvalidBasisPoints",
+      "msg": "Basis points can be at most 10,000"
+    },
+    {
+      "code": 6016,
+      "name": "JackpotAccountMissing",
+      "msg": "The jackpot token account is required while the jackpot is enabled"
+    },
+    {
+      "code": 6017,
+      "name": "InvalidReferrer",
+      "msg": "The referrer must be an existing player other than yourself"
+    },
+    {
+      "code": 6018,
+      "name": "ReferrerAccountMissing",
+      "msg": "The referrer's profile and token account are required to pay them"
+    },
+    {
+      "code": 6019,
+      "name": "InvalidTeamName",
+      "msg": "Team names must be between 1 and 32 bytes"
+    },
+    {
+      "code": 6020,
+      "name": "InvalidTeamSize",
+      "msg": "Teams can have between 1 and 50 members"
+    },
+    {
+      "code": 6021,
+      "name": "TeamFull",
+      "msg": "The team is full"
+    },
+    {
+      "code": 6022,
+      "name": "AlreadyInTeam",
+      "msg": "Already in a team"
+    },
+    {
+      "code": 6023,
+      "name": "NotInTeam",
+      "msg": "Not a member of this team"
+    },
+    {
+      "code": 6024,
+      "name": "TeamChangeTooSoon",
+      "msg": "Changed team too recently"
+    },
+    {
+      "code": 6025,
+      "name": "TeamAccountMissing",
+      "msg": "The player's team account is required"
+    },
+    {
+      "code": 6026,
+      "name": "ProfileNotInitialized",
+      "msg": "The player profile must be initialized first"
+    },
+    {
+      "code": 6027,
+      "name": "TooManyAllowedPrograms",
+      "msg": "Too many programs in the instruction allowlist"
+    },
+    {
+      "code": 6028,
+      "name": "CannotAllowGameProgram",
+      "msg": "The game program can't be in its own allowlist"
+    },
+    {
+      "code": 6029,
+      "name": "MultipleGameInstructions",
+      "msg": "Only one game instruction is allowed per transaction"
+    },
+    {
+      "code": 6030,
+      "name": "InstructionNotAllowedBefore",
+      "msg": "Program not allowed before the game instruction"
+    },
+    {
+      "code": 6031,
+      "name": "InstructionNotAllowedAfter",
+      "msg": "Program not allowed after the game instruction"
+    },
+    {
+      "code": 6032,
+      "name": "PlayerNotAuthorized",
+      "msg": "The player must sign, or a valid session key must sign for them"
+    },
+    {
+      "code": 6033,
+      "name": "InvalidSessionKey",
+      "msg": "The session key doesn't match the session"
+    },
+    {
+      "code": 6034,
+      "name": "SessionExpired",
+      "msg": "The session has expired"
+    },
+    {
+      "code": 6035,
+      "name": "SessionGameLimitReached",
+      "msg": "The session has played its maximum number of games"
+    },
+    {
+      "code": 6036,
+      "name": "SessionStakeLimitReached",
+      "msg": "The session has staked its maximum amount"
+    },
+    {
+      "code": 6037,
+      "name": "SlotPlayLimitReached",
+      "msg": "Too many plays across the vault this slot"
+    },
+    {
+      "code": 6038,
+      "name": "WindowPlayLimitReached",
+      "msg": "Too many plays across the vault in the recent slot window"
+    },
+    {
+      "code": 6039,
+      "name": "LargeDropLimitReached",
+      "msg": "Too many large payouts from the vault this slot"
+    },
+    {
+      "code": 6040,
+      "name": "BurnRateLockout",
+      "msg": "Locked out after failing the burn rate check"
+    },
+    {
+      "code": 6041,
+      "name": "InvalidSprtConfig",
+      "msg": "SPRT needs an odds ratio above 1 and error rates between 0 and 1"
+    },
+    {
+      "code": 6042,
+      "name": "VaultPaused",
+      "msg": "The vault is paused"
+    },
+    {
+      "code": 6043,
+      "name": "UnsanctionedCpi",
+      "msg": "Only enabled integrators can call the game through CPI, using play_game_cpi"
+    },
+    {
+      "code": 6044,
+      "name": "NotCalledViaCpi",
+      "msg": "play_game_cpi has to be called through CPI from the integrator program"
+    },
+    {
+      "code": 6045,
+      "name": "IntegratorAccountMissing",
+      "msg": "The integrator, integrator authority and integrator token account are required"
+    },
+    {
+      "code": 6046,
+      "name": "IntegratorDisabled",
+      "msg": "The integrator is disabled"
+    },
+    {
+      "code": 6047,
+      "name": "InvalidIntegratorAuthority",
+      "msg": "The integrator authority or token account doesn't match the integrator"
+    },
+    {
+      "code": 6048,
+      "name": "InsufficientTreasuryBalance",
+      "msg": "Not enough lamports in the treasury above the rent exemption minimum"
+    },
+    {
+      "code": 6049,
+      "name": "DepositNotClaimable",
+      "msg": "The deposit can't be claimed yet"
+    },
+    {
+      "code": 6050,
+      "name": "InvalidDepositor",
+      "msg": "The deposit is refunded to whoever paid it"
+    },
+    {
+      "code": 6051,
+      "name": "StillInTeam",
+      "msg": "Leave the team before closing the profile"
+    },
+    {
+      "code": 6052,
+      "name": "DepositNotClaimed",
+      "msg": "Claim the deposit before closing the profile"
+    },
+    {
+      "code": 6053,
+      "name": "HistoryTooLong",
+      "msg": "Player history is longer than the maximum"
+    },
+    {
+      "code": 6054,
+      "name": "AchievementNotEarned",
+      "msg": "The achievement hasn't been earned"
+    },
+    {
+      "code": 6055,
+      "name": "AchievementAlreadyClaimed",
+      "msg": "The achievement has already been claimed"
+    },
+    {
+      "code": 6056,
+      "name": "InvalidNickname",
+      "msg": "Nicknames must be 3 to 20 ascii letters, digits, underscores or dashes"
+    },
+    {
+      "code": 6057,
+      "name": "InvalidAvatarUri",
+      "msg": "Avatar URIs must be https, ipfs or ar links of at most 128 bytes"
+    },
+    {
+      "code": 6058,
+      "name": "NicknameTaken",
+      "msg": "The nickname is taken"
+    },
+    {
+      "code": 6059,
+      "name": "PreviousNicknameRecordMissing",
+      "msg": "The previous nickname record is required when changing nickname"
+    },
+    {
+      "code": 6060,
+      "name": "InvalidPreviousNicknameRecord",
+      "msg": "The previous nickname record doesn't match the current nickname"
+    },
+    {
+      "code": 6061,
+      "name": "LinkedProfileMissing",
+      "msg": "The wallet is linked, pass the primary wallet's profile as the linked profile"
+    },
+    {
+      "code": 6062,
+      "name": "InvalidLinkedProfile",
+      "msg": "The linked profile doesn't match the one the wallet is linked to"
+    },
+    {
+      "code": 6063,
+      "name": "ProfileLinked",
+      "msg": "Linked profiles can't be closed"
+    },
+    {
+      "code": 6064,
+      "name": "InvalidWalletLink",
+      "msg": "Only an unlinked wallet without linked wallets of its own can be linked to an unlinked primary"
+    },
+    {
+      "code": 6065,
+      "name": "LegacyProfileNotMigrated",
+      "msg": "Move the legacy profile over with migrate_profile first"
+    },
+    {
+      "code": 6066,
+      "name": "VaultAlreadyMigrated",
+      "msg": "The vault is already in the current layout"
+    },
+    {
+      "code": 6067,
+      "name": "InvalidLegacyVault",
+      "msg": "The vault isn't a legacy vault of this creator"
+    },
+    {
+      "code": 6068,
+      "name": "JackpotNotInitialized",
+      "msg": "Initialize the jackpot before enabling it"
+    },
+    {
+      "code": 6069,
+      "name": "ProgramNotPassive",
+      "msg": "Only passive programs like memo can be allowed after the game instruction"
+    },
+    {
+      "code": 6070,
+      "name": "InvalidSessionStake",
+      "msg": "The session's max total stake is too large"
+    },
+    {
+      "code": 6071,
+      "name": "InvalidDepositConfig",
+      "msg": "The deposit refund needs at least enough games to pass the significance test"
+    },
+    {
+      "code": 6072,
+      "name": "HasReferrals",
+      "msg": "Profiles that referred players can't be closed"
+    },
+    {
+      "code": 6073,
+      "name": "InvalidLegacyProfile",
+      "msg": "The legacy profile isn't in the originally deployed layout"
+    },
+    {
+      "code": 6074,
+      "name": "InvalidJackpotConfig",
+      "msg": "The jackpot needs both a share of lost stakes and odds, or neither"
+    },
+    {
+      "code": 6075,
+      "name": "WalletNotLinked",
+      "msg": "Only wallets linked to a primary wallet pass a linked profile"
     }
   ]
 };
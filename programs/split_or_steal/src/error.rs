@@ -28,4 +28,130 @@ pub enum SplitOrStealError {
     MintOver,
     #[msg("Submitting transaction too close together, only allowed once per 3 slots")]
     PlayedAgainTooSoon,
+    #[msg("A batch needs at least one game")]
+    EmptyBatch,
+    #[msg("Too many games in one batch")]
+    BatchTooLarge,
+    #[msg("Basis points can be at most 10,000")]
+    InvalidBasisPoints,
+    #[msg("The jackpot token account is required while the jackpot is enabled")]
+    JackpotAccountMissing,
+    #[msg("The referrer must be an existing player other than yourself")]
+    InvalidReferrer,
+    #[msg("The referrer's profile and token account are required to pay them")]
+    ReferrerAccountMissing,
+    #[msg("Team names must be between 1 and 32 bytes")]
+    InvalidTeamName,
+    #[msg("Teams can have between 1 and 50 members")]
+    InvalidTeamSize,
+    #[msg("The team is full")]
+    TeamFull,
+    #[msg("Already in a team")]
+    AlreadyInTeam,
+    #[msg("Not a member of this team")]
+    NotInTeam,
+    #[msg("Changed team too recently")]
+    TeamChangeTooSoon,
+    #[msg("The player's team account is required")]
+    TeamAccountMissing,
+    #[msg("The player profile must be initialized first")]
+    ProfileNotInitialized,
+    #[msg("Too many programs in the instruction allowlist")]
+    TooManyAllowedPrograms,
+    #[msg("The game program can't be in its own allowlist")]
+    CannotAllowGameProgram,
+    #[msg("Only one game instruction is allowed per transaction")]
+    MultipleGameInstructions,
+    #[msg("Program not allowed before the game instruction")]
+    InstructionNotAllowedBefore,
+    #[msg("Program not allowed after the game instruction")]
+    InstructionNotAllowedAfter,
+    #[msg("The player must sign, or a valid session key must sign for them")]
+    PlayerNotAuthorized,
+    #[msg("The session key doesn't match the session")]
+    InvalidSessionKey,
+    #[msg("The session has expired")]
+    SessionExpired,
+    #[msg("The session has played its maximum number of games")]
+    SessionGameLimitReached,
+    #[msg("The session has staked its maximum amount")]
+    SessionStakeLimitReached,
+    #[msg("Too many plays across the vault this slot")]
+    SlotPlayLimitReached,
+    #[msg("Too many plays across the vault in the recent slot window")]
+    WindowPlayLimitReached,
+    #[msg("Too many large payouts from the vault this slot")]
+    LargeDropLimitReached,
+    #[msg("Locked out after failing the burn rate check")]
+    BurnRateLockout,
+    #[msg("SPRT needs an odds ratio above 1 and error rates between 0 and 1")]
+    InvalidSprtConfig,
+    #[msg("The vault is paused")]
+    VaultPaused,
+    #[msg("Only enabled integrators can call the game through CPI, using play_game_cpi")]
+    UnsanctionedCpi,
+    #[msg("play_game_cpi has to be called through CPI from the integrator program")]
+    NotCalledViaCpi,
+    #[msg("The integrator, integrator authority and integrator token account are required")]
+    IntegratorAccountMissing,
+    #[msg("The integrator is disabled")]
+    IntegratorDisabled,
+    #[msg("The integrator authority or token account doesn't match the integrator")]
+    InvalidIntegratorAuthority,
+    #[msg("Not enough lamports in the treasury above the rent exemption minimum")]
+    InsufficientTreasuryBalance,
+    #[msg("The deposit can't be claimed yet")]
+    DepositNotClaimable,
+    #[msg("The deposit is refunded to whoever paid it")]
+    InvalidDepositor,
+    #[msg("Leave the team before closing the profile")]
+    StillInTeam,
+    #[msg("Claim the deposit before closing the profile")]
+    DepositNotClaimed,
+    #[msg("Player history is longer than the maximum")]
+    HistoryTooLong,
+    #[msg("The achievement hasn't been earned")]
+    AchievementNotEarned,
+    #[msg("The achievement has already been claimed")]
+    AchievementAlreadyClaimed,
+    #[msg("Nicknames must be 3 to 20 ascii letters, digits, underscores or dashes")]
+    InvalidNickname,
+    #[msg("Avatar URIs must be https, ipfs or ar links of at most 128 bytes")]
+    InvalidAvatarUri,
+    #[msg("The nickname is taken")]
+    NicknameTaken,
+    #[msg("The previous nickname record is required when changing nickname")]
+    PreviousNicknameRecordMissing,
+    #[msg("The previous nickname record doesn't match the current nickname")]
+    InvalidPreviousNicknameRecord,
+    #[msg("The wallet is linked, pass the primary wallet's profile as the linked profile")]
+    LinkedProfileMissing,
+    #[msg("The linked profile doesn't match the one the wallet is linked to")]
+    InvalidLinkedProfile,
+    #[msg("Linked profiles can't be closed")]
+    ProfileLinked,
+    #[msg("Only an unlinked wallet without linked wallets of its own can be linked to an unlinked primary")]
+    InvalidWalletLink,
+    #[msg("Move the legacy profile over with migrate_profile first")]
+    LegacyProfileNotMigrated,
+    #[msg("The vault is already in the current layout")]
+    VaultAlreadyMigrated,
+    #[msg("The vault isn't a legacy vault of this creator")]
+    InvalidLegacyVault,
+    #[msg("Initialize the jackpot before enabling it")]
+    JackpotNotInitialized,
+    #[msg("Only passive programs like memo can be allowed after the game instruction")]
+    ProgramNotPassive,
+    #[msg("The session's max total stake is too large")]
+    InvalidSessionStake,
+    #[msg("The deposit refund needs at least enough games to pass the significance test")]
+    InvalidDepositConfig,
+    #[msg("Profiles that referred players can't be closed")]
+    HasReferrals,
+    #[msg("The legacy profile isn't in the originally deployed layout")]
+    InvalidLegacyProfile,
+    #[msg("The jackpot needs both a share of lost stakes and odds, or neither")]
+    InvalidJackpotConfig,
+    #[msg("Only wallets linked to a primary wallet pass a linked profile")]
+    WalletNotLinked,
 }
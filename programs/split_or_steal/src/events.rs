@@ -0,0 +1,40 @@
+use super::*;
+
+// The creator needs to look into it and unpause with set_vault_paused
+#[event]
+pub struct VaultAutoPaused {
+    pub anomaly: Anomaly,
+    pub slot: u64,
+    pub stats: AnomalyStats,
+}
+
+// After every game, so frontends can keep the player's stats up to date without fetching the profile
+#[event]
+pub struct PlayerStatsUpdated {
+    pub player: Pubkey,
+    pub num_games: u32,
+    pub stats: PlayerStats,
+}
+
+// Claim the badge with claim_achievement
+#[event]
+pub struct AchievementsEarned {
+    pub player: Pubkey,
+    // Bits from Achievement::flag
+    pub achievements: u32,
+}
+
+// Flags a profile for operators to review after failing either luck check, the player is locked out until locked_until_slot
+#[event]
+pub struct BurnRateCheckFailed {
+    pub player: Pubkey,
+    pub num_failures: u32,
+    pub flagged_slot: u64,
+    pub locked_until_slot: u64,
+    pub failed_burn_rate_check: bool,
+    pub failed_winnings_check: bool,
+    pub failed_sprt_check: bool,
+    pub num_games: u32,
+    pub tokens_gained: u64,
+    pub tokens_burned: u64,
+}
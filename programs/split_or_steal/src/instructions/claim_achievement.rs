@@ -0,0 +1,256 @@
+use super::*;
+
+#[derive(Accounts)]
+#[instruction(achievement: Achievement)]
+pub struct ClaimAchievement<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    #[account(
+        seeds = [b"lord of the gourd"],
+        bump = game_vault.bump
+    )]
+    pub game_vault: Box<Account<'info, GameVault>>,
+    #[account(
+        mut,
+        seeds = [b"profile", game_vault.key().as_ref(), player.key().as_ref()],
+        bump = player_profile.bump
+    )]
+    pub player_profile: Box<Account<'info, PlayerProfile>>,
+    /// CHECK: Created in the function, Anchor can't init Token-2022 mints with extensions.
+    /// One non-transferable Token-2022 mint per player and achievement, carrying its own metadata
+    /// with the vault as update authority so every badge can be verified from its seeds
+    #[account(
+        mut,
+        seeds = [b"achievement", player.key().as_ref(), &[achievement as u8]],
+        bump
+    )]
+    pub achievement_mint: UncheckedAccount<'info>,
+    /// CHECK: The Token-2022 group every badge joins, set up by initialize_achievement_collection.
+    /// Token-2022 checks it's a group with the vault as update authority
+    #[account(
+        mut,
+        seeds = [b"achievement_collection", game_vault.key().as_ref()],
+        bump
+    )]
+    pub achievement_collection: UncheckedAccount<'info>,
+    /// CHECK: The player's associated token account for the badge, the associated token program checks the address
+    #[account(mut)]
+    pub achievement_token_account: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl ClaimAchievement<'_> {
+    pub fn handle(ctx: Context<Self>, achievement: Achievement) -> Result<()> {
+        let ClaimAchievement {
+            player,
+            player_profile,
+            game_vault,
+            achievement_mint,
+            achievement_collection,
+            achievement_token_account,
+            token_program,
+            associated_token_program,
+            system_program,
+        } = ctx.accounts;
+
+        require!(
+            player_profile.has_achievement(achievement),
+            SplitOrStealError::AchievementNotEarned
+        );
+        require!(
+            player_profile.claimed_achievements & achievement.flag() == 0,
+            SplitOrStealError::AchievementAlreadyClaimed
+        );
+        player_profile.claimed_achievements |= achievement.flag();
+
+        let player_key = player.key();
+        let mint_seeds = &[
+            b"achievement".as_ref(),
+            player_key.as_ref(),
+            &[achievement as u8],
+            &[ctx.bumps.achievement_mint],
+        ];
+        let mint_signer = &[&mint_seeds[..]];
+        let vault_seeds = &[b"lord of the gourd".as_ref(), &[game_vault.bump]];
+        let vault_signer = &[&vault_seeds[..]];
+
+        // Token-2022 grows the mint for the metadata and group membership itself, but the rent has to be there up front
+        let metadata = TokenMetadata {
+            name: achievement.name().to_string(),
+            symbol: ACHIEVEMENT_SYMBOL.to_string(),
+            uri: ACHIEVEMENT_URI.to_string(),
+            ..Default::default()
+        };
+        let mint_extensions = [
+            ExtensionType::NonTransferable,
+            ExtensionType::MetadataPointer,
+            ExtensionType::GroupMemberPointer,
+        ];
+        let mint_space = ExtensionType::try_calculate_account_len::<Mint2022>(&mint_extensions)?;
+        let rent_space = ExtensionType::try_calculate_account_len::<Mint2022>(
+            &[&mint_extensions[..], &[ExtensionType::TokenGroupMember]].concat(),
+        )? + metadata.tlv_size_of()?;
+        create_pda_account(
+            &player.to_account_info(),
+            &achievement_mint.to_account_info(),
+            &system_program.to_account_info(),
+            mint_space,
+            Rent::get()?.minimum_balance(rent_space),
+            &token_program.key(),
+            mint_signer,
+        )?;
+
+        // Extensions go on before the mint is initialized, non-transferable makes the badge soulbound
+        let mint_infos = [
+            achievement_mint.to_account_info(),
+            token_program.to_account_info(),
+        ];
+        invoke(
+            &spl_token_2022::instruction::initialize_non_transferable_mint(
+                &token_program.key(),
+                &achievement_mint.key(),
+            )?,
+            &mint_infos,
+        )?;
+        invoke(
+            &metadata_pointer::instruction::initialize(
+                &token_program.key(),
+                &achievement_mint.key(),
+                Some(game_vault.key()),
+                Some(achievement_mint.key()),
+            )?,
+            &mint_infos,
+        )?;
+        invoke(
+            &group_member_pointer::instruction::initialize(
+                &token_program.key(),
+                &achievement_mint.key(),
+                Some(game_vault.key()),
+                Some(achievement_mint.key()),
+            )?,
+            &mint_infos,
+        )?;
+        invoke(
+            &spl_token_2022::instruction::initialize_mint2(
+                &token_program.key(),
+                &achievement_mint.key(),
+                &game_vault.key(),
+                None,
+                0,
+            )?,
+            &mint_infos,
+        )?;
+        invoke_signed(
+            &spl_token_metadata_interface::instruction::initialize(
+                &token_program.key(),
+                &achievement_mint.key(),
+                &game_vault.key(),
+                &achievement_mint.key(),
+                &game_vault.key(),
+                metadata.name,
+                metadata.symbol,
+                metadata.uri,
+            ),
+            &[
+                achievement_mint.to_account_info(),
+                game_vault.to_account_info(),
+                token_program.to_account_info(),
+            ],
+            vault_signer,
+        )?;
+        // The vault is both the badge's mint authority and the collection's update authority
+        invoke_signed(
+            &spl_token_group_interface::instruction::initialize_member(
+                &token_program.key(),
+                &achievement_mint.key(),
+                &achievement_mint.key(),
+                &game_vault.key(),
+                &achievement_collection.key(),
+                &game_vault.key(),
+            ),
+            &[
+                achievement_mint.to_account_info(),
+                game_vault.to_account_info(),
+                achievement_collection.to_account_info(),
+                token_program.to_account_info(),
+            ],
+            vault_signer,
+        )?;
+
+        let cpi_accounts = associated_token::Create {
+            payer: player.to_account_info(),
+            associated_token: achievement_token_account.to_account_info(),
+            authority: player.to_account_info(),
+            mint: achievement_mint.to_account_info(),
+            system_program: system_program.to_account_info(),
+            token_program: token_program.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(associated_token_program.to_account_info(), cpi_accounts);
+        associated_token::create(cpi_ctx)?;
+
+        let cpi_accounts = token_2022::MintTo {
+            mint: achievement_mint.to_account_info(),
+            to: achievement_token_account.to_account_info(),
+            authority: game_vault.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            cpi_accounts,
+            vault_signer,
+        );
+        token_2022::mint_to(cpi_ctx, 1)?;
+
+        // Nothing more can ever be minted, a supply of one for good
+        let cpi_accounts = token_2022::SetAuthority {
+            current_authority: game_vault.to_account_info(),
+            account_or_mint: achievement_mint.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            cpi_accounts,
+            vault_signer,
+        );
+        token_2022::set_authority(
+            cpi_ctx,
+            token_2022::spl_token_2022::instruction::AuthorityType::MintTokens,
+            None,
+        )?;
+
+        msg!("Claimed the {:?} achievement", achievement);
+        Ok(())
+    }
+}
+
+// Sending lamports to the address first would make a plain create_account fail and block the claim for good,
+// topping up and then allocating and assigning works whatever is already there
+pub(crate) fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    lamports: u64,
+    owner: &Pubkey,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let top_up = lamports.saturating_sub(account.lamports());
+    if top_up > 0 {
+        let cpi_accounts = system_program::Transfer {
+            from: payer.clone(),
+            to: account.clone(),
+        };
+        let cpi_ctx = CpiContext::new(system_program.clone(), cpi_accounts);
+        system_program::transfer(cpi_ctx, top_up)?;
+    }
+    let cpi_accounts = system_program::Allocate {
+        account_to_allocate: account.clone(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(system_program.clone(), cpi_accounts, signer_seeds);
+    system_program::allocate(cpi_ctx, space as u64)?;
+    let cpi_accounts = system_program::Assign {
+        account_to_assign: account.clone(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(system_program.clone(), cpi_accounts, signer_seeds);
+    system_program::assign(cpi_ctx, owner)
+}
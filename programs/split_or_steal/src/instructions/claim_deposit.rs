@@ -0,0 +1,49 @@
+use super::*;
+
+#[derive(Accounts)]
+pub struct ClaimDeposit<'info> {
+    pub player: Signer<'info>,
+    #[account(
+        seeds = [b"lord of the gourd"],
+        bump = game_vault.bump
+    )]
+    pub game_vault: Box<Account<'info, GameVault>>,
+    #[account(
+        mut,
+        seeds = [b"profile", game_vault.key().as_ref(), player.key().as_ref()],
+        bump = player_profile.bump
+    )]
+    pub player_profile: Box<Account<'info, PlayerProfile>>,
+    // Whoever paid the deposit, the player or the relayer that sponsored them
+    #[account(mut)]
+    pub depositor: SystemAccount<'info>,
+}
+
+impl ClaimDeposit<'_> {
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let player_profile = &mut ctx.accounts.player_profile;
+        require!(
+            player_profile.can_claim_deposit(&ctx.accounts.game_vault.config),
+            SplitOrStealError::DepositNotClaimable
+        );
+        require_keys_eq!(
+            ctx.accounts.depositor.key(),
+            player_profile.depositor,
+            SplitOrStealError::InvalidDepositor
+        );
+
+        let deposit = player_profile.deposit_lamports;
+        player_profile.deposit_lamports = 0;
+
+        // The program owns the profile so the lamports can be moved directly, rent stays behind
+        **player_profile.to_account_info().try_borrow_mut_lamports()? -= deposit;
+        **ctx.accounts.depositor.try_borrow_mut_lamports()? += deposit;
+
+        msg!(
+            "Refunded {} lamport deposit to {}",
+            deposit,
+            ctx.accounts.depositor.key()
+        );
+        Ok(())
+    }
+}
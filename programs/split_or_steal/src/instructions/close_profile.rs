@@ -0,0 +1,87 @@
+use super::*;
+
+#[derive(Accounts)]
+pub struct CloseProfile<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    #[account(
+        seeds = [b"lord of the gourd"],
+        bump = game_vault.bump
+    )]
+    pub game_vault: Box<Account<'info, GameVault>>,
+    #[account(
+        mut,
+        close = player,
+        seeds = [b"profile", game_vault.key().as_ref(), player.key().as_ref()],
+        bump = player_profile.bump
+    )]
+    pub player_profile: Box<Account<'info, PlayerProfile>>,
+    #[account(
+        init_if_needed,
+        payer = player,
+        seeds = [b"tombstone", game_vault.key().as_ref(), player.key().as_ref()],
+        bump,
+        space = 8 + std::mem::size_of::<ProfileTombstone>()
+    )]
+    pub profile_tombstone: Box<Account<'info, ProfileTombstone>>,
+    // A deposit that can't be claimed is forfeited here
+    #[account(
+        mut,
+        seeds = [b"treasury", game_vault.key().as_ref()],
+        bump = treasury.bump
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl CloseProfile<'_> {
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let CloseProfile {
+            game_vault,
+            player_profile,
+            profile_tombstone,
+            treasury,
+            ..
+        } = ctx.accounts;
+
+        // Team member counts belong to other accounts, sort those out first
+        require_keys_eq!(
+            player_profile.team,
+            Pubkey::default(),
+            SplitOrStealError::StillInTeam
+        );
+        // Linked wallets' games live on the primary's profile, which has to stay around for them
+        require!(
+            !player_profile.is_linked() && player_profile.num_linked_wallets == 0,
+            SplitOrStealError::ProfileLinked
+        );
+        // Referred players keep paying their referrer's profile, it has to stay around for them
+        require_eq!(
+            player_profile.num_referrals,
+            0,
+            SplitOrStealError::HasReferrals
+        );
+        let deposit = player_profile.deposit_lamports;
+        if deposit > 0 {
+            require!(
+                !player_profile.can_claim_deposit(&game_vault.config),
+                SplitOrStealError::DepositNotClaimed
+            );
+            // Closing can't be a way around the refund conditions
+            player_profile.deposit_lamports = 0;
+            **player_profile.to_account_info().try_borrow_mut_lamports()? -= deposit;
+            **treasury.to_account_info().try_borrow_mut_lamports()? += deposit;
+            treasury.record_fee(deposit);
+            msg!("Forfeited {} lamport deposit to the treasury", deposit);
+        }
+        // Closing during a lockout would still be caught by the tombstone, but there's no reason to allow it
+        require!(
+            !player_profile.is_locked_out(Clock::get()?.slot),
+            SplitOrStealError::BurnRateLockout
+        );
+
+        profile_tombstone.bump = ctx.bumps.profile_tombstone;
+        profile_tombstone.record(player_profile);
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use super::*;
+
+#[derive(Accounts)]
+#[instruction(session_key: Pubkey)]
+pub struct CreateSession<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    #[account(
+        init,
+        payer = player,
+        seeds = [b"session", player.key().as_ref(), session_key.as_ref()],
+        bump,
+        space = 8 + std::mem::size_of::<PlaySession>()
+    )]
+    pub session: Box<Account<'info, PlaySession>>,
+    #[account(
+        mut,
+        associated_token::authority = player,
+        associated_token::mint = mint
+    )]
+    pub player_token_account: Box<Account<'info, TokenAccount>>,
+    pub mint: Box<Account<'info, Mint>>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl CreateSession<'_> {
+    pub fn handle(
+        ctx: Context<Self>,
+        session_key: Pubkey,
+        expiry_slot: u64,
+        max_total_stake: u64,
+        max_games: u32,
+    ) -> Result<()> {
+        let CreateSession {
+            player,
+            session,
+            player_token_account,
+            token_program,
+            ..
+        } = ctx.accounts;
+
+        let current_slot = Clock::get()?.slot;
+        require_gt!(expiry_slot, current_slot, SplitOrStealError::SessionExpired);
+        require_gt!(max_games, 0, SplitOrStealError::SessionGameLimitReached);
+
+        // Same units as play_game, whole tokens
+        let converted_max_total_stake = max_total_stake
+            .checked_mul(u64::pow(10, DEFAULT_DECIMALS.into()))
+            .ok_or(SplitOrStealError::InvalidSessionStake)?;
+        session.initialize(
+            player.key(),
+            session_key,
+            ctx.bumps.session,
+            expiry_slot,
+            converted_max_total_stake,
+            max_games,
+        );
+
+        // The session PDA becomes the delegate so only this program can spend the stake
+        let cpi_accounts = Approve {
+            to: player_token_account.to_account_info(),
+            delegate: session.to_account_info(),
+            authority: player.to_account_info(),
+        };
+        let cpi_program = token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token::approve(cpi_ctx, converted_max_total_stake)?;
+
+        msg!(
+            "Created session for key {}, Expiry Slot: {}, Max Total Stake: {}, Max Games: {}",
+            session_key,
+            expiry_slot,
+            converted_max_total_stake,
+            max_games
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use super::*;
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateTeam<'info> {
+    #[account(mut)]
+    pub leader: Signer<'info>,
+    #[account(
+        seeds = [b"lord of the gourd"],
+        bump = game_vault.bump
+    )]
+    pub game_vault: Box<Account<'info, GameVault>>,
+    #[account(
+        mut,
+        seeds = [b"profile", game_vault.key().as_ref(), leader.key().as_ref()],
+        bump = leader_profile.bump
+    )]
+    pub leader_profile: Box<Account<'info, PlayerProfile>>,
+    #[account(
+        init,
+        payer = leader,
+        seeds = [b"team", name.as_bytes()],
+        bump,
+        space = 8 + std::mem::size_of::<Team>()
+    )]
+    pub team: Box<Account<'info, Team>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl CreateTeam<'_> {
+    pub fn handle(ctx: Context<Self>, name: String, max_members: u16) -> Result<()> {
+        let CreateTeam {
+            leader,
+            leader_profile,
+            team,
+            ..
+        } = ctx.accounts;
+
+        team.initialize(leader.key(), &name, max_members, ctx.bumps.team)?;
+        // The leader is the first member
+        JoinTeam::join(leader_profile, team)?;
+
+        msg!("Created team {} with max members {}", name, max_members);
+        Ok(())
+    }
+}
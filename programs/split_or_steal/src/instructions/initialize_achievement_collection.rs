@@ -0,0 +1,133 @@
+use super::*;
+
+#[derive(Accounts)]
+pub struct InitializeAchievementCollection<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        seeds = [b"lord of the gourd"],
+        bump = game_vault.bump,
+        has_one = creator
+    )]
+    pub game_vault: Box<Account<'info, GameVault>>,
+    /// CHECK: Created in the function, Anchor can't init Token-2022 mints with extensions.
+    /// A Token-2022 group mint with the vault as update authority, every badge claim_achievement mints joins it
+    #[account(
+        mut,
+        seeds = [b"achievement_collection", game_vault.key().as_ref()],
+        bump
+    )]
+    pub achievement_collection: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+impl InitializeAchievementCollection<'_> {
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let InitializeAchievementCollection {
+            creator,
+            game_vault,
+            achievement_collection,
+            token_program,
+            system_program,
+        } = ctx.accounts;
+
+        let game_vault_key = game_vault.key();
+        let collection_seeds = &[
+            b"achievement_collection".as_ref(),
+            game_vault_key.as_ref(),
+            &[ctx.bumps.achievement_collection],
+        ];
+        let collection_signer = &[&collection_seeds[..]];
+        let vault_seeds = &[b"lord of the gourd".as_ref(), &[game_vault.bump]];
+        let vault_signer = &[&vault_seeds[..]];
+
+        let metadata = TokenMetadata {
+            name: ACHIEVEMENT_COLLECTION_NAME.to_string(),
+            symbol: ACHIEVEMENT_SYMBOL.to_string(),
+            uri: ACHIEVEMENT_URI.to_string(),
+            ..Default::default()
+        };
+        let mint_extensions = [ExtensionType::MetadataPointer, ExtensionType::GroupPointer];
+        let mint_space = ExtensionType::try_calculate_account_len::<Mint2022>(&mint_extensions)?;
+        let rent_space = ExtensionType::try_calculate_account_len::<Mint2022>(
+            &[&mint_extensions[..], &[ExtensionType::TokenGroup]].concat(),
+        )? + metadata.tlv_size_of()?;
+        create_pda_account(
+            &creator.to_account_info(),
+            &achievement_collection.to_account_info(),
+            &system_program.to_account_info(),
+            mint_space,
+            Rent::get()?.minimum_balance(rent_space),
+            &token_program.key(),
+            collection_signer,
+        )?;
+
+        let mint_infos = [
+            achievement_collection.to_account_info(),
+            token_program.to_account_info(),
+        ];
+        invoke(
+            &metadata_pointer::instruction::initialize(
+                &token_program.key(),
+                &achievement_collection.key(),
+                Some(game_vault.key()),
+                Some(achievement_collection.key()),
+            )?,
+            &mint_infos,
+        )?;
+        invoke(
+            &group_pointer::instruction::initialize(
+                &token_program.key(),
+                &achievement_collection.key(),
+                Some(game_vault.key()),
+                Some(achievement_collection.key()),
+            )?,
+            &mint_infos,
+        )?;
+        // Never minted, the vault only keeps the mint authority because initializing the group needs its signature
+        invoke(
+            &spl_token_2022::instruction::initialize_mint2(
+                &token_program.key(),
+                &achievement_collection.key(),
+                &game_vault.key(),
+                None,
+                0,
+            )?,
+            &mint_infos,
+        )?;
+
+        let signer_infos = [
+            achievement_collection.to_account_info(),
+            game_vault.to_account_info(),
+            token_program.to_account_info(),
+        ];
+        invoke_signed(
+            &spl_token_metadata_interface::instruction::initialize(
+                &token_program.key(),
+                &achievement_collection.key(),
+                &game_vault.key(),
+                &achievement_collection.key(),
+                &game_vault.key(),
+                metadata.name,
+                metadata.symbol,
+                metadata.uri,
+            ),
+            &signer_infos,
+            vault_signer,
+        )?;
+        invoke_signed(
+            &spl_token_group_interface::instruction::initialize_group(
+                &token_program.key(),
+                &achievement_collection.key(),
+                &achievement_collection.key(),
+                &game_vault.key(),
+                Some(game_vault.key()),
+                u32::MAX,
+            ),
+            &signer_infos,
+            vault_signer,
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+use super::*;
+
+#[derive(Accounts)]
+pub struct InitializeJackpot<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"lord of the gourd"],
+        bump = game_vault.bump,
+        has_one = creator
+    )]
+    pub game_vault: Box<Account<'info, GameVault>>,
+    #[account(
+        token::mint = mint,
+        seeds = [game_vault.key().as_ref()],
+        bump = game_vault.token_bump
+    )]
+    pub game_vault_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = creator,
+        seeds = [b"jackpot", game_vault.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = game_vault
+    )]
+    pub jackpot_token_account: Box<Account<'info, TokenAccount>>,
+    pub mint: Box<Account<'info, Mint>>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl InitializeJackpot<'_> {
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        ctx.accounts.game_vault.jackpot_bump = ctx.bumps.jackpot_token_account;
+        Ok(())
+    }
+}
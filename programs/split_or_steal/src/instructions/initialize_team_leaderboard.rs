@@ -0,0 +1,31 @@
+use super::*;
+
+#[derive(Accounts)]
+pub struct InitializeTeamLeaderboard<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        seeds = [b"lord of the gourd"],
+        bump = game_vault.bump,
+        has_one = creator
+    )]
+    pub game_vault: Box<Account<'info, GameVault>>,
+    #[account(
+        init,
+        payer = creator,
+        seeds = [b"team leaderboard", game_vault.key().as_ref()],
+        bump,
+        space = 8 + std::mem::size_of::<TeamLeaderboard>()
+    )]
+    pub team_leaderboard: Box<Account<'info, TeamLeaderboard>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl InitializeTeamLeaderboard<'_> {
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        ctx.accounts
+            .team_leaderboard
+            .initialize(ctx.bumps.team_leaderboard);
+        Ok(())
+    }
+}
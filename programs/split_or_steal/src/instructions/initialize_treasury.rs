@@ -0,0 +1,29 @@
+use super::*;
+
+#[derive(Accounts)]
+pub struct InitializeTreasury<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        seeds = [b"lord of the gourd"],
+        bump = game_vault.bump,
+        has_one = creator
+    )]
+    pub game_vault: Box<Account<'info, GameVault>>,
+    #[account(
+        init,
+        payer = creator,
+        seeds = [b"treasury", game_vault.key().as_ref()],
+        bump,
+        space = 8 + std::mem::size_of::<Treasury>()
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl InitializeTreasury<'_> {
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        ctx.accounts.treasury.bump = ctx.bumps.treasury;
+        Ok(())
+    }
+}
@@ -84,6 +84,7 @@ impl InitializeVault<'_> {
             ctx.bumps.game_vault,
             ctx.bumps.game_vault_token_account,
         );
+        game_vault.season_start_slot = Clock::get()?.slot;
 
         // Transfer initial tokens to the vault's token account
         let cpi_accounts: Transfer = Transfer {
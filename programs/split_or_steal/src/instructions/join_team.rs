@@ -0,0 +1,52 @@
+use super::*;
+
+#[derive(Accounts)]
+pub struct JoinTeam<'info> {
+    pub player: Signer<'info>,
+    #[account(
+        seeds = [b"lord of the gourd"],
+        bump = game_vault.bump
+    )]
+    pub game_vault: Box<Account<'info, GameVault>>,
+    #[account(
+        mut,
+        seeds = [b"profile", game_vault.key().as_ref(), player.key().as_ref()],
+        bump = player_profile.bump
+    )]
+    pub player_profile: Box<Account<'info, PlayerProfile>>,
+    #[account(mut)]
+    pub team: Box<Account<'info, Team>>,
+}
+
+impl JoinTeam<'_> {
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let JoinTeam {
+            player_profile,
+            team,
+            ..
+        } = ctx.accounts;
+        Self::join(player_profile, team)
+    }
+
+    pub(crate) fn join(player_profile: &mut PlayerProfile, team: &mut Account<Team>) -> Result<()> {
+        require!(
+            player_profile.is_initialized,
+            SplitOrStealError::ProfileNotInitialized
+        );
+        require_keys_eq!(
+            player_profile.team,
+            Pubkey::default(),
+            SplitOrStealError::AlreadyInTeam
+        );
+        let current_slot = Clock::get()?.slot;
+        require!(
+            player_profile.can_change_team(current_slot),
+            SplitOrStealError::TeamChangeTooSoon
+        );
+
+        team.add_member()?;
+        player_profile.team = team.key();
+        player_profile.team_changed_slot = current_slot;
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use super::*;
+
+#[derive(Accounts)]
+pub struct LeaveTeam<'info> {
+    pub player: Signer<'info>,
+    #[account(
+        seeds = [b"lord of the gourd"],
+        bump = game_vault.bump
+    )]
+    pub game_vault: Box<Account<'info, GameVault>>,
+    #[account(
+        mut,
+        seeds = [b"profile", game_vault.key().as_ref(), player.key().as_ref()],
+        bump = player_profile.bump
+    )]
+    pub player_profile: Box<Account<'info, PlayerProfile>>,
+    #[account(mut)]
+    pub team: Box<Account<'info, Team>>,
+}
+
+impl LeaveTeam<'_> {
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let LeaveTeam {
+            player_profile,
+            team,
+            ..
+        } = ctx.accounts;
+
+        require_keys_eq!(
+            player_profile.team,
+            team.key(),
+            SplitOrStealError::NotInTeam
+        );
+        let current_slot = Clock::get()?.slot;
+        require!(
+            player_profile.can_change_team(current_slot),
+            SplitOrStealError::TeamChangeTooSoon
+        );
+
+        team.remove_member();
+        player_profile.team = Pubkey::default();
+        player_profile.team_changed_slot = current_slot;
+        Ok(())
+    }
+}
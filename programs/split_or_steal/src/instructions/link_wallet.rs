@@ -0,0 +1,59 @@
+use super::*;
+
+#[derive(Accounts)]
+pub struct LinkWallet<'info> {
+    pub primary: Signer<'info>,
+    #[account(
+        seeds = [b"lord of the gourd"],
+        bump = game_vault.bump
+    )]
+    pub game_vault: Box<Account<'info, GameVault>>,
+    #[account(
+        mut,
+        seeds = [b"profile", game_vault.key().as_ref(), primary.key().as_ref()],
+        bump = primary_profile.bump
+    )]
+    pub primary_profile: Box<Account<'info, PlayerProfile>>,
+    // Both wallets sign so nobody can pin their luck on someone else's profile
+    pub secondary: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"profile", game_vault.key().as_ref(), secondary.key().as_ref()],
+        bump = secondary_profile.bump
+    )]
+    pub secondary_profile: Box<Account<'info, PlayerProfile>>,
+}
+
+impl LinkWallet<'_> {
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let LinkWallet {
+            primary,
+            primary_profile,
+            secondary,
+            secondary_profile,
+            ..
+        } = ctx.accounts;
+
+        // Only one level of linking, so every wallet plays on a profile that isn't linked itself
+        require!(
+            primary.key() != secondary.key()
+                && !primary_profile.is_linked()
+                && !secondary_profile.is_linked()
+                && secondary_profile.num_linked_wallets == 0,
+            SplitOrStealError::InvalidWalletLink
+        );
+        // Team member counts go by profile, the secondary plays in the primary's team from now on
+        require_keys_eq!(
+            secondary_profile.team,
+            Pubkey::default(),
+            SplitOrStealError::StillInTeam
+        );
+
+        primary_profile.merge_luck_stats(secondary_profile);
+        primary_profile.num_linked_wallets += 1;
+        secondary_profile.primary_wallet = primary.key();
+
+        msg!("Linked {} to {}", secondary.key(), primary.key());
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use super::*;
+use anchor_lang::Discriminator;
+
+#[derive(Accounts)]
+pub struct MigrateProfile<'info> {
+    // Gets the legacy profile's rent back since it's their account, whoever pays for the migration
+    #[account(mut)]
+    pub player: Signer<'info>,
+    // Pays for the new profile, can be a relayer
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [b"lord of the gourd"],
+        bump = game_vault.bump
+    )]
+    pub game_vault: Box<Account<'info, GameVault>>,
+    /// CHECK: Profiles used to be seeded by the player alone and are still in the original layout, decoded and closed in the function
+    #[account(mut, seeds = [player.key().as_ref()], bump, owner = crate::ID)]
+    pub legacy_profile: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"profile", game_vault.key().as_ref(), player.key().as_ref()],
+        bump,
+        space = 8 + std::mem::size_of::<PlayerProfile>()
+    )]
+    pub player_profile: Box<Account<'info, PlayerProfile>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl MigrateProfile<'_> {
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let MigrateProfile {
+            player,
+            legacy_profile,
+            player_profile,
+            ..
+        } = ctx.accounts;
+
+        let legacy = {
+            let data = legacy_profile.try_borrow_data()?;
+            require!(
+                data.len() >= 8 && data[..8] == PlayerProfile::DISCRIMINATOR,
+                SplitOrStealError::InvalidLegacyProfile
+            );
+            LegacyPlayerProfile::deserialize(&mut &data[8..])
+                .map_err(|_| SplitOrStealError::InvalidLegacyProfile)?
+        };
+        player_profile.set_inner(PlayerProfile::from_legacy(
+            &legacy,
+            ctx.bumps.player_profile,
+        ));
+
+        // Close the legacy profile by hand since it can't be loaded as an account to close
+        let legacy_info = legacy_profile.to_account_info();
+        **player.to_account_info().try_borrow_mut_lamports()? += legacy_info.lamports();
+        **legacy_info.try_borrow_mut_lamports()? = 0;
+        legacy_info.assign(&system_program::ID);
+        legacy_info.realloc(0, false)?;
+
+        msg!("Migrated profile with {} games", player_profile.num_games);
+        Ok(())
+    }
+}
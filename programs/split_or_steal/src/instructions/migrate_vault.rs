@@ -0,0 +1,64 @@
+use super::*;
+use anchor_lang::Discriminator;
+
+#[derive(Accounts)]
+pub struct MigrateVault<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    /// CHECK: Still in the original layout so it can't be loaded as a GameVault, decoded and checked in the function
+    #[account(mut, seeds = [b"lord of the gourd"], bump, owner = crate::ID)]
+    pub game_vault: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl MigrateVault<'_> {
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let MigrateVault {
+            creator,
+            game_vault,
+            system_program,
+        } = ctx.accounts;
+
+        let new_space = 8 + std::mem::size_of::<GameVault>();
+        let legacy = {
+            let data = game_vault.try_borrow_data()?;
+            require!(
+                data.len() < new_space,
+                SplitOrStealError::VaultAlreadyMigrated
+            );
+            require!(
+                data.len() >= 8 && data[..8] == GameVault::DISCRIMINATOR,
+                SplitOrStealError::InvalidLegacyVault
+            );
+            LegacyGameVault::deserialize(&mut &data[8..])
+                .map_err(|_| SplitOrStealError::InvalidLegacyVault)?
+        };
+        require_keys_eq!(
+            legacy.creator,
+            creator.key(),
+            SplitOrStealError::InvalidLegacyVault
+        );
+
+        let new_minimum = Rent::get()?.minimum_balance(new_space);
+        let lamports = game_vault.lamports();
+        if new_minimum > lamports {
+            let cpi_accounts = system_program::Transfer {
+                from: creator.to_account_info(),
+                to: game_vault.to_account_info(),
+            };
+            let cpi_context = CpiContext::new(system_program.to_account_info(), cpi_accounts);
+            system_program::transfer(cpi_context, new_minimum - lamports)?;
+        }
+        game_vault.realloc(new_space, true)?;
+
+        let vault = GameVault::from_legacy(&legacy, Clock::get()?.slot);
+        let mut data = game_vault.try_borrow_mut_data()?;
+        vault.try_serialize(&mut &mut data[..])?;
+
+        msg!(
+            "Migrated the vault, {} tokens left to mint",
+            vault.mint_remaining
+        );
+        Ok(())
+    }
+}
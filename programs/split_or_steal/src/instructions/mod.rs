@@ -1,7 +1,58 @@
 pub use super::*;
 
+pub mod claim_achievement;
+pub mod claim_deposit;
+pub mod close_profile;
+pub mod create_session;
+pub mod create_team;
+pub mod initialize_achievement_collection;
+pub mod initialize_jackpot;
+pub mod initialize_team_leaderboard;
+pub mod initialize_treasury;
 pub mod initialize_vault;
+pub mod join_team;
+pub mod leave_team;
+pub mod link_wallet;
+pub mod migrate_profile;
+pub mod migrate_vault;
+pub mod play_batch;
 pub mod play_game;
+pub mod play_game_cpi;
+pub mod resize_player_history;
+pub mod revoke_session;
+pub mod set_instruction_allowlist;
+pub mod set_integrator;
+pub mod set_profile_metadata;
+pub mod set_vault_paused;
+pub mod start_season;
+pub mod update_config;
+pub mod update_team_leaderboard;
+pub mod withdraw_treasury;
 
+pub use claim_achievement::*;
+pub use claim_deposit::*;
+pub use close_profile::*;
+pub use create_session::*;
+pub use create_team::*;
+pub use initialize_achievement_collection::*;
+pub use initialize_jackpot::*;
+pub use initialize_team_leaderboard::*;
+pub use initialize_treasury::*;
 pub use initialize_vault::*;
+pub use join_team::*;
+pub use leave_team::*;
+pub use link_wallet::*;
+pub use migrate_profile::*;
+pub use migrate_vault::*;
+pub use play_batch::*;
 pub use play_game::*;
+pub use resize_player_history::*;
+pub use revoke_session::*;
+pub use set_instruction_allowlist::*;
+pub use set_integrator::*;
+pub use set_profile_metadata::*;
+pub use set_vault_paused::*;
+pub use start_season::*;
+pub use update_config::*;
+pub use update_team_leaderboard::*;
+pub use withdraw_treasury::*;
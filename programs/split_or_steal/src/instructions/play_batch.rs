@@ -0,0 +1,51 @@
+use super::*;
+
+pub const MAX_BATCH_SIZE: usize = 10;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct BatchGame {
+    pub choice: Choice,
+    pub stake_amount: u16,
+}
+
+impl PlayGame<'_> {
+    // Plays every game in one instruction, each game still counts towards the cooldown
+    pub fn handle_batch(ctx: Context<Self>, games: Vec<BatchGame>) -> Result<Vec<GameResult>> {
+        require!(!games.is_empty(), SplitOrStealError::EmptyBatch);
+        require_gte!(
+            MAX_BATCH_SIZE,
+            games.len(),
+            SplitOrStealError::BatchTooLarge
+        );
+
+        ctx.accounts
+            .begin_play(ctx.program_id, ctx.bumps.player_profile, false)?;
+
+        let stake_amounts: Vec<u16> = games.iter().map(|game| game.stake_amount).collect();
+        ctx.accounts.check_stakes(&stake_amounts)?;
+        ctx.accounts.check_cooldown(games.len() as u64)?;
+
+        // One generator for the whole batch, every game takes fresh draws from it
+        let mut rng = ctx.accounts.seed_rng()?;
+        let mut results = Vec::with_capacity(games.len());
+        for game in games {
+            if ctx.accounts.game_vault.mint_remaining == 0 {
+                msg!("Mint finished, skipping the rest of the batch");
+                break;
+            }
+            if ctx
+                .accounts
+                .player_profile
+                .is_locked_out(Clock::get()?.slot)
+            {
+                msg!("Player locked out, skipping the rest of the batch");
+                break;
+            }
+            let result = ctx
+                .accounts
+                .play_round(game.choice, game.stake_amount, &mut rng)?;
+            results.push(result);
+        }
+        Ok(results)
+    }
+}
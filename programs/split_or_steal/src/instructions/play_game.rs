@@ -2,32 +2,49 @@ use super::*;
 
 const COMPUTE_BUDGET_PROGRAM_ID: Pubkey = pubkey!("ComputeBudget111111111111111111111111111111");
 const NUM_SLOTS_COOLDOWN: u64 = 3;
+// SystemInstruction::AdvanceNonceAccount, bincode encodes the variant as a u32
+const ADVANCE_NONCE_ACCOUNT_DISCRIMINANT: u32 = 4;
+// Make the account more expensive to create to discourage sybilling
+pub const PROFILE_CREATION_FEE: u64 = 6_000_000;
 
 #[derive(Accounts)]
 pub struct PlayGame<'info> {
+    /// CHECK: Has to sign unless a session key signs for it, checked in begin_play.
+    /// Pass the same key as payer when not using a relayer
+    pub player: UncheckedAccount<'info>,
+    // Pays for rent and the profile creation fee, can be a relayer sponsoring the player
     #[account(mut)]
-    pub player: Signer<'info>,
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"lord of the gourd"],
+        bump = game_vault.bump
+    )]
+    pub game_vault: Box<Account<'info, GameVault>>,
     #[account(
         init_if_needed,
-        payer = player,
-        seeds = [player.key().as_ref()],
+        payer = payer,
+        seeds = [b"profile", game_vault.key().as_ref(), player.key().as_ref()],
         bump,
         space = 8 + std::mem::size_of::<PlayerProfile>()
     )]
     pub player_profile: Box<Account<'info, PlayerProfile>>,
+    /// CHECK: Only exists once the player has closed a profile, a re-created profile inherits its stats
+    #[account(
+        seeds = [b"tombstone", game_vault.key().as_ref(), player.key().as_ref()],
+        bump
+    )]
+    pub profile_tombstone: UncheckedAccount<'info>,
+    /// CHECK: Profile from before profiles were seeded by vault, has to be moved over with migrate_profile first
+    #[account(seeds = [player.key().as_ref()], bump)]
+    pub legacy_profile: UncheckedAccount<'info>,
     #[account(
         init_if_needed,
-        payer = player,
+        payer = payer,
         associated_token::authority = player,
         associated_token::mint = mint
     )]
     pub player_token_account: Box<Account<'info, TokenAccount>>,
-    #[account(
-        mut,
-        seeds = [b"lord of the gourd"],
-        bump = game_vault.bump
-    )]
-    pub game_vault: Box<Account<'info, GameVault>>,
     #[account(
         mut,
         token::mint = mint,
@@ -36,8 +53,13 @@ pub struct PlayGame<'info> {
         bump = game_vault.token_bump
     )]
     pub game_vault_token_account: Box<Account<'info, TokenAccount>>,
-    #[account(mut)]
-    pub creator: SystemAccount<'info>,
+    // Profile creation fees go here, see withdraw_treasury
+    #[account(
+        mut,
+        seeds = [b"treasury", game_vault.key().as_ref()],
+        bump = treasury.bump
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
     #[account(mut)]
     pub mint: Box<Account<'info, Mint>>,
     /// CHECK: slot hashes too big to deserialize normally
@@ -49,109 +71,617 @@ pub struct PlayGame<'info> {
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
+    // Only needed while the jackpot is enabled in the vault config
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = game_vault,
+        seeds = [b"jackpot", game_vault.key().as_ref()],
+        bump = game_vault.jackpot_bump
+    )]
+    pub jackpot_token_account: Option<Box<Account<'info, TokenAccount>>>,
+    // The referrer accounts are only needed when creating a profile with a referrer,
+    // or when paying the referrer of an existing profile
+    #[account(mut)]
+    pub referrer: Option<SystemAccount<'info>>,
+    #[account(mut)]
+    pub referrer_profile: Option<Box<Account<'info, PlayerProfile>>>,
+    #[account(mut, token::mint = mint)]
+    pub referrer_token_account: Option<Box<Account<'info, TokenAccount>>>,
+    // Required when the player is in a team, so the team gets credited
+    #[account(mut)]
+    pub team: Option<Box<Account<'info, Team>>>,
+    // Without it only compute budget instructions may come before play_game and nothing after
+    #[account(
+        seeds = [b"allowlist", game_vault.key().as_ref()],
+        bump = instruction_allowlist.bump
+    )]
+    pub instruction_allowlist: Option<Box<Account<'info, InstructionAllowlist>>>,
+    // Both needed when a session key plays instead of the player
+    pub session_key: Option<Signer<'info>>,
+    #[account(
+        mut,
+        has_one = player,
+        seeds = [b"session", player.key().as_ref(), session.session_key.as_ref()],
+        bump = session.bump
+    )]
+    pub session: Option<Box<Account<'info, PlaySession>>>,
+    // All three needed for play_game_cpi, the authority is the integrator program's PDA signing through the CPI
+    #[account(
+        mut,
+        seeds = [b"integrator", game_vault.key().as_ref(), integrator.program_id.as_ref()],
+        bump = integrator.bump
+    )]
+    pub integrator: Option<Box<Account<'info, Integrator>>>,
+    pub integrator_authority: Option<Signer<'info>>,
+    #[account(mut, token::mint = mint)]
+    pub integrator_token_account: Option<Box<Account<'info, TokenAccount>>>,
+    // Only needed by players who set one up with resize_player_history
+    #[account(
+        mut,
+        seeds = [b"history", game_vault.key().as_ref(), player.key().as_ref()],
+        bump = player_history.bump
+    )]
+    pub player_history: Option<Box<Account<'info, PlayerHistory>>>,
+    // Required once the player has linked their wallet to a primary wallet, it's the primary's profile
+    #[account(mut)]
+    pub linked_profile: Option<Box<Account<'info, PlayerProfile>>>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct GameResult {
+    pub stake_amount: u64,
+    pub player_choice: Choice,
+    pub opponent_choice: Choice,
+    // Tokens transferred to the player after the referrer's and integrator's shares, zero if the stake was burned
+    pub outcome_amount: u64,
+    pub burned_amount: u64,
+    // Part of the lost stake sent to the jackpot instead of burned
+    pub jackpot_contribution: u64,
+    pub jackpot_payout: u64,
+    // The referrer's share of the winnings, taken out of the outcome amount
+    pub referral_payout: u64,
+    // Integrator's revenue share when played through play_game_cpi, also taken out of the outcome amount
+    pub integrator_payout: u64,
+    pub got_split_bonus: bool,
+    pub passes_burn_rate_check: bool,
+    pub passes_winnings_check: bool,
+    pub passes_sprt_check: bool,
+}
+
+impl GameResult {
+    pub fn passes_luck_checks(&self) -> bool {
+        self.passes_burn_rate_check && self.passes_winnings_check && self.passes_sprt_check
+    }
 }
 
 impl PlayGame<'_> {
     pub fn handle(ctx: Context<Self>, choice: Choice, stake_amount: u16) -> Result<()> {
+        ctx.accounts
+            .begin_play(ctx.program_id, ctx.bumps.player_profile, false)?;
+
+        ctx.accounts.check_stakes(&[stake_amount])?;
+        ctx.accounts.check_cooldown(1)?;
+
+        let mut rng = ctx.accounts.seed_rng()?;
+        ctx.accounts.play_round(choice, stake_amount, &mut rng)?;
+        Ok(())
+    }
+
+    pub(crate) fn begin_play(
+        &mut self,
+        program_id: &Pubkey,
+        profile_bump: u8,
+        via_cpi: bool,
+    ) -> Result<()> {
+        let top_level_program_id = self.check_stack_height(program_id, via_cpi)?;
         let PlayGame {
             player,
+            payer,
             player_profile,
-            player_token_account,
+            profile_tombstone,
+            legacy_profile,
             game_vault,
-            game_vault_token_account,
-            creator,
-            mint,
-            slot_hashes,
+            treasury,
             instructions,
             system_program,
+            jackpot_token_account,
+            referrer,
+            referrer_profile,
+            team,
+            instruction_allowlist,
+            session_key,
+            session,
+            linked_profile,
             ..
-        } = ctx.accounts;
+        } = self;
 
         require_gt!(game_vault.mint_remaining, 0, SplitOrStealError::MintOver);
+        require!(!game_vault.paused, SplitOrStealError::VaultPaused);
+
+        // Either the player signs or a live session key signs for them
+        if !player.is_signer {
+            match (session, session_key) {
+                (Some(session), Some(session_key)) => {
+                    require_keys_eq!(
+                        session.session_key,
+                        session_key.key(),
+                        SplitOrStealError::InvalidSessionKey
+                    );
+                    require!(
+                        session.is_active(Clock::get()?.slot),
+                        SplitOrStealError::SessionExpired
+                    );
+                }
+                _ => return err!(SplitOrStealError::PlayerNotAuthorized),
+            }
+        }
+        if game_vault.config.jackpot_enabled() {
+            require!(
+                jackpot_token_account.is_some(),
+                SplitOrStealError::JackpotAccountMissing
+            );
+        }
 
-        // Check that this is the only play_game instruction and only compute budget otherwise
+        // Check that this is the only play_game instruction and only compute budget or allowlisted programs otherwise
         // Either it's the first and only instruction or the first couple of instructions are compute budget program
-        Self::check_top_level_instruction(&instructions.to_account_info(), ctx.program_id)?;
+        Self::check_top_level_instruction(
+            &instructions.to_account_info(),
+            program_id,
+            &top_level_program_id,
+            instruction_allowlist
+                .as_deref()
+                .map(|allowlist| &**allowlist),
+        )?;
 
         // Initialize player profile if not already initialized
         if !player_profile.is_initialized {
-            let cpi_accounts = system_program::Transfer {
-                from: player.to_account_info(),
-                to: creator.to_account_info(),
-            };
-            let cpi_context = CpiContext::new(system_program.to_account_info(), cpi_accounts);
-            // Make the account more expensive to create to discourage sybilling
-            system_program::transfer(cpi_context, 6_000_000)?;
-            player_profile.initialize(ctx.bumps.player_profile);
+            // Starting a fresh profile next to a legacy one would start its luck statistics over
+            require!(
+                legacy_profile.data_is_empty(),
+                SplitOrStealError::LegacyProfileNotMigrated
+            );
+            let mut treasury_fee = PROFILE_CREATION_FEE;
+            player_profile.initialize(profile_bump);
+            if !profile_tombstone.data_is_empty() {
+                // Only this program can allocate its own PDA, so the data is a tombstone
+                let tombstone = ProfileTombstone::try_deserialize(
+                    &mut &profile_tombstone.try_borrow_data()?[..],
+                )?;
+                tombstone.restore(player_profile);
+            }
+
+            if let Some(referrer) = referrer {
+                require_keys_neq!(
+                    referrer.key(),
+                    player.key(),
+                    SplitOrStealError::InvalidReferrer
+                );
+                let referrer_profile = referrer_profile
+                    .as_mut()
+                    .ok_or(SplitOrStealError::ReferrerAccountMissing)?;
+                Self::check_referrer_profile(&referrer.key(), referrer_profile, &game_vault.key())?;
+
+                let referral_fee =
+                    apply_basis_points(PROFILE_CREATION_FEE, game_vault.config.referral_fee_bps);
+                if referral_fee != 0 {
+                    let cpi_accounts = system_program::Transfer {
+                        from: payer.to_account_info(),
+                        to: referrer.to_account_info(),
+                    };
+                    let cpi_context =
+                        CpiContext::new(system_program.to_account_info(), cpi_accounts);
+                    system_program::transfer(cpi_context, referral_fee)?;
+                    treasury_fee -= referral_fee;
+                }
+
+                player_profile.referrer = referrer.key();
+                referrer_profile.num_referrals += 1;
+                referrer_profile.referral_lamports_earned += referral_fee;
+            }
+
+            if game_vault.config.profile_fee_as_deposit {
+                // Held on the profile itself until the player earns it back
+                let cpi_accounts = system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: player_profile.to_account_info(),
+                };
+                let cpi_context = CpiContext::new(system_program.to_account_info(), cpi_accounts);
+                system_program::transfer(cpi_context, treasury_fee)?;
+                player_profile.deposit_lamports = treasury_fee;
+                player_profile.depositor = payer.key();
+            } else {
+                let cpi_accounts = system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: treasury.to_account_info(),
+                };
+                let cpi_context = CpiContext::new(system_program.to_account_info(), cpi_accounts);
+                system_program::transfer(cpi_context, treasury_fee)?;
+                treasury.record_fee(treasury_fee);
+            }
         }
 
-        // Check they passed in a value between 0 and 100
-        require_gte!(100, stake_amount, SplitOrStealError::StakeAmountTooHigh);
-        let converted_stake_amount: u64 = stake_amount as u64 * u64::pow(10, 9);
+        match linked_profile {
+            Some(linked_profile) => {
+                require!(
+                    player_profile.is_linked(),
+                    SplitOrStealError::WalletNotLinked
+                );
+                let expected_profile = Pubkey::create_program_address(
+                    &[
+                        b"profile",
+                        game_vault.key().as_ref(),
+                        player_profile.primary_wallet.as_ref(),
+                        &[linked_profile.bump],
+                    ],
+                    &crate::ID,
+                )
+                .map_err(|_| SplitOrStealError::InvalidLinkedProfile)?;
+                require_keys_eq!(
+                    linked_profile.key(),
+                    expected_profile,
+                    SplitOrStealError::InvalidLinkedProfile
+                );
+                // Everything from here on, cooldown and luck checks included, uses the primary's profile.
+                // Each account is still written back to its own address when the instruction exits
+                std::mem::swap(player_profile, linked_profile);
+            }
+            None => require!(
+                !player_profile.is_linked(),
+                SplitOrStealError::LinkedProfileMissing
+            ),
+        }
+
+        match team {
+            Some(team) => require_keys_eq!(
+                team.key(),
+                player_profile.team,
+                SplitOrStealError::NotInTeam
+            ),
+            None => require_keys_eq!(
+                player_profile.team,
+                Pubkey::default(),
+                SplitOrStealError::TeamAccountMissing
+            ),
+        }
+        Ok(())
+    }
+
+    // Referrers have to be existing players, so their profile must sit at the usual profile address
+    fn check_referrer_profile(
+        referrer: &Pubkey,
+        referrer_profile: &Account<PlayerProfile>,
+        game_vault: &Pubkey,
+    ) -> Result<()> {
+        let expected_profile = Pubkey::create_program_address(
+            &[
+                b"profile",
+                game_vault.as_ref(),
+                referrer.as_ref(),
+                &[referrer_profile.bump],
+            ],
+            &crate::ID,
+        )
+        .map_err(|_| SplitOrStealError::InvalidReferrer)?;
+        require_keys_eq!(
+            expected_profile,
+            referrer_profile.key(),
+            SplitOrStealError::InvalidReferrer
+        );
+        require!(
+            referrer_profile.is_initialized,
+            SplitOrStealError::InvalidReferrer
+        );
+        Ok(())
+    }
+
+    // Checks every stake in the instruction, the balance has to cover all of them being burned
+    pub(crate) fn check_stakes(&mut self, stake_amounts: &[u16]) -> Result<()> {
+        let mut total_stake_amount: u64 = 0;
+        for stake_amount in stake_amounts {
+            // Check they passed in a value between 0 and 100
+            require_gte!(100, *stake_amount, SplitOrStealError::StakeAmountTooHigh);
+            total_stake_amount += *stake_amount as u64 * u64::pow(10, 9);
+        }
         // Check if player has enough tokens
         require_gte!(
-            player_token_account.amount,
-            converted_stake_amount,
+            self.player_token_account.amount,
+            total_stake_amount,
             SplitOrStealError::InsufficientTokens
         );
+        // Sessions are capped on games and stake, the delegated amount alone doesn't limit games
+        if !self.player.is_signer {
+            if let Some(session) = self.session.as_mut() {
+                session.record_plays(stake_amounts.len() as u32, total_stake_amount)?;
+            }
+        }
+        Ok(())
+    }
+
+    // Every game costs a full cooldown, a batch pushes the next play back by one cooldown per game
+    pub(crate) fn check_cooldown(&mut self, num_games: u64) -> Result<()> {
         // Only play once per 8 slots / 3 seconds-ish
         let current_slot = Clock::get()?.slot;
+        require!(
+            !self.player_profile.is_locked_out(current_slot),
+            SplitOrStealError::BurnRateLockout
+        );
         require_gte!(
             current_slot,
-            player_profile.last_played_slot + NUM_SLOTS_COOLDOWN,
+            self.player_profile.last_played_slot + NUM_SLOTS_COOLDOWN,
             SplitOrStealError::PlayedAgainTooSoon
         );
-        player_profile.last_played_slot = current_slot;
-
-        // It was too annoying to get zero copy working with array sizes 1,000 and 101.
-        // These are the main ones we care about anyway.
-        if stake_amount == 0 {
-            game_vault.num_zero_stakes = game_vault.num_zero_stakes.saturating_add(1);
-        } else if stake_amount == 100 {
-            game_vault.num_hundred_stakes = game_vault.num_hundred_stakes.saturating_add(1);
-        }
+        self.player_profile.last_played_slot = current_slot + (num_games - 1) * NUM_SLOTS_COOLDOWN;
+        Ok(())
+    }
 
+    pub(crate) fn seed_rng(&self) -> Result<ChaCha8Rng> {
         // Initialize PRNG using blockhash, player pubkey, and remaining supply
         // Get the current slot
         let clock = Clock::get()?;
         let timestamp = clock.unix_timestamp;
 
-        let slot_hashes_data = slot_hashes.try_borrow_data()?;
+        let slot_hashes_data = self.slot_hashes.try_borrow_data()?;
         let mut previous_slot_hash = [0u8; 32];
         // This is not the previous slot hash lol
         previous_slot_hash.copy_from_slice(&slot_hashes_data[16..48]);
         let seed_array: [u8; 32] = hashv(&[
             &timestamp.to_le_bytes(),
             &previous_slot_hash,
-            player.key().as_ref(),
-            &game_vault.mint_remaining.to_le_bytes(),
+            self.player.key().as_ref(),
+            &self.game_vault.mint_remaining.to_le_bytes(),
         ])
         .to_bytes();
         let seed_u64 = u64::from_le_bytes(seed_array[..8].try_into().unwrap());
 
         // Initialize the pseudo-random number generator
-        let mut rng = ChaCha8Rng::seed_from_u64(seed_u64);
+        Ok(ChaCha8Rng::seed_from_u64(seed_u64))
+    }
+
+    pub(crate) fn play_round(
+        &mut self,
+        choice: Choice,
+        stake_amount: u16,
+        rng: &mut ChaCha8Rng,
+    ) -> Result<GameResult> {
+        let current_slot = Clock::get()?.slot;
+        let config = self.game_vault.config;
+        self.game_vault
+            .throttle
+            .record_play(current_slot, &config)?;
+
+        let mint_remaining_before = self.game_vault.mint_remaining;
+        // Before the game's own entry goes into the ring buffer
+        let num_splits = self.game_vault.num_splits;
+        let mut result = self.play_matchup(choice, stake_amount, rng)?;
+        if !result.passes_luck_checks() {
+            self.lock_out_player(current_slot, &result);
+        }
+        self.game_vault.record_game();
+        self.player_profile.record_season_game(
+            self.game_vault.season,
+            result.outcome_amount,
+            result.burned_amount + result.jackpot_contribution,
+            result.got_split_bonus,
+        );
+        self.player_profile.stats.record_game(
+            choice,
+            result.opponent_choice,
+            result.outcome_amount,
+            result.burned_amount + result.jackpot_contribution,
+        );
+        emit!(PlayerStatsUpdated {
+            player: self.player.key(),
+            num_games: self.player_profile.num_games,
+            stats: self.player_profile.stats,
+        });
+        let achievements = self.player_profile.record_achievements(
+            num_splits,
+            result.outcome_amount,
+            result.got_split_bonus,
+        );
+        if achievements != 0 {
+            emit!(AchievementsEarned {
+                player: self.player.key(),
+                achievements,
+            });
+        }
+        if let Some(team) = self.team.as_mut() {
+            team.record_game(
+                choice,
+                result.outcome_amount,
+                result.burned_amount + result.jackpot_contribution,
+            );
+        }
+        self.check_vault_anomalies(current_slot);
+        self.pay_referrer(result.referral_payout)?;
+        self.pay_integrator(result.integrator_payout)?;
+        let mint_drop = mint_remaining_before - self.game_vault.mint_remaining;
+        self.game_vault
+            .throttle
+            .record_mint_drop(current_slot, mint_drop, &config)?;
+        // Anyone failing the burn rate check sits out the jackpot draw too
+        if result.passes_luck_checks() {
+            result.jackpot_payout = self.draw_jackpot(rng)?;
+        }
+        if let Some(player_history) = self.player_history.as_mut() {
+            player_history.record(HistoryEntry {
+                slot: current_slot,
+                stake_amount,
+                player_choice: result.player_choice,
+                opponent_choice: result.opponent_choice,
+                outcome_amount: result.outcome_amount,
+                got_split_bonus: result.got_split_bonus,
+            });
+        }
+        Ok(result)
+    }
+
+    // Pausing doesn't fail this game, it stops every game after it until the creator unpauses
+    fn check_vault_anomalies(&mut self, current_slot: u64) {
+        let game_vault = &mut self.game_vault;
+        let config = game_vault.config;
+        if !config.anomaly_pause_enabled()
+            || game_vault.paused
+            || game_vault.anomaly_stats.num_games < config.anomaly_min_games
+        {
+            return;
+        }
+        if let Some(anomaly) = game_vault
+            .anomaly_stats
+            .find_anomaly(config.anomaly_pause_z_score)
+        {
+            msg!("Vault wide {:?} anomaly, pausing the vault", anomaly);
+            game_vault.paused = true;
+            emit!(VaultAutoPaused {
+                anomaly,
+                slot: current_slot,
+                stats: game_vault.anomaly_stats,
+            });
+        }
+    }
+
+    fn lock_out_player(&mut self, current_slot: u64, result: &GameResult) {
+        let player_profile = &mut self.player_profile;
+        let locked_until_slot =
+            player_profile.record_failed_burn_rate_check(current_slot, !result.passes_sprt_check);
+        msg!("Player locked out until slot {}", locked_until_slot);
+        emit!(BurnRateCheckFailed {
+            player: self.player.key(),
+            num_failures: player_profile.burn_rate_check_failures,
+            flagged_slot: current_slot,
+            locked_until_slot,
+            failed_burn_rate_check: !result.passes_burn_rate_check,
+            failed_winnings_check: !result.passes_winnings_check,
+            failed_sprt_check: !result.passes_sprt_check,
+            num_games: player_profile.num_games,
+            tokens_gained: player_profile.tokens_gained,
+            tokens_burned: player_profile.tokens_burned,
+        });
+    }
+
+    fn play_matchup(
+        &mut self,
+        choice: Choice,
+        stake_amount: u16,
+        rng: &mut ChaCha8Rng,
+    ) -> Result<GameResult> {
+        let PlayGame {
+            player,
+            player_profile,
+            player_token_account,
+            game_vault,
+            game_vault_token_account,
+            mint,
+            token_program,
+            jackpot_token_account,
+            session,
+            integrator,
+            ..
+        } = self;
+
+        // The player signs for their own stake, otherwise the session signs as the token account delegate
+        let delegated_session = if player.is_signer {
+            None
+        } else {
+            session.as_deref()
+        };
+        let stake_authority = match delegated_session {
+            Some(session) => session.to_account_info(),
+            None => player.to_account_info(),
+        };
+        let session_bump = [delegated_session.map_or(0, |session| session.bump)];
+        let session_seeds: Vec<&[u8]> = match delegated_session {
+            Some(session) => vec![
+                b"session".as_ref(),
+                session.player.as_ref(),
+                session.session_key.as_ref(),
+                &session_bump,
+            ],
+            None => vec![],
+        };
+        let session_signer = [&session_seeds[..]];
+        let stake_signer: &[&[&[u8]]] = if delegated_session.is_some() {
+            &session_signer
+        } else {
+            &[]
+        };
+
+        let converted_stake_amount: u64 = stake_amount as u64 * u64::pow(10, 9);
+
+        // It was too annoying to get zero copy working with array sizes 1,000 and 101.
+        // These are the main ones we care about anyway.
+        if stake_amount == 0 {
+            game_vault.num_zero_stakes = game_vault.num_zero_stakes.saturating_add(1);
+        } else if stake_amount == 100 {
+            game_vault.num_hundred_stakes = game_vault.num_hundred_stakes.saturating_add(1);
+        }
+
         let random_index: u16 = rng.gen_range(0..1000);
 
         // Calculate return based on player and opponent choices
         let opponent_choice = game_vault.get_entry_at(random_index);
 
+        let num_splits = game_vault.num_splits;
+        // What the player is paid if the opponent splits, not counting any split bonus
+        let payout_value = match choice {
+            Choice::Split => game_vault.calculate_split_value(converted_stake_amount),
+            Choice::Steal => game_vault.calculate_steal_value(converted_stake_amount),
+        };
+
         let passes_burn_rate_check = player_profile.passes_burn_rate_check();
+        let passes_winnings_check = player_profile.passes_winnings_check();
+        let config = game_vault.config;
+        let passes_sprt_check = !config.sprt_enabled() || player_profile.luck_sprt.passes(&config);
+        let passes_luck_checks =
+            passes_burn_rate_check && passes_winnings_check && passes_sprt_check;
+        // A game forced to a loss says nothing about the player's luck, an opponent split wasn't a win
+        if config.sprt_enabled() && passes_luck_checks {
+            player_profile.luck_sprt.update(
+                opponent_choice == Choice::Split,
+                game_vault.num_splits,
+                &config,
+            );
+        }
         // If the opponent stole, then no matter what the player chose they lose
-        if opponent_choice == Choice::Steal || !passes_burn_rate_check {
+        if opponent_choice == Choice::Steal || !passes_luck_checks {
             if opponent_choice != Choice::Steal && !passes_burn_rate_check {
                 msg!("Failed burn rate check")
             }
-            if converted_stake_amount != 0 {
+            if opponent_choice != Choice::Steal && !passes_winnings_check {
+                msg!("Failed winnings check")
+            }
+            if opponent_choice != Choice::Steal && !passes_sprt_check {
+                msg!("Failed SPRT luck check")
+            }
+            let mut jackpot_contribution = 0;
+            if let Some(jackpot_token_account) = jackpot_token_account {
+                jackpot_contribution =
+                    game_vault.calculate_jackpot_contribution(converted_stake_amount);
+                if jackpot_contribution != 0 {
+                    // Move the jackpot's share of the stake instead of burning it
+                    let cpi_accounts = Transfer {
+                        from: player_token_account.to_account_info(),
+                        to: jackpot_token_account.to_account_info(),
+                        authority: stake_authority.clone(),
+                    };
+                    let cpi_program = token_program.to_account_info();
+                    let cpi_ctx =
+                        CpiContext::new_with_signer(cpi_program, cpi_accounts, stake_signer);
+                    token::transfer(cpi_ctx, jackpot_contribution)?;
+                }
+            }
+            let burn_amount = converted_stake_amount - jackpot_contribution;
+            if burn_amount != 0 {
                 // Burn the stake amount from the player's token account
                 let cpi_accounts = Burn {
                     mint: mint.to_account_info(),
                     from: player_token_account.to_account_info(),
-                    authority: player.to_account_info(),
+                    authority: stake_authority,
                 };
-                let cpi_program = ctx.accounts.token_program.to_account_info();
-                let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-                token::burn(cpi_ctx, converted_stake_amount)?;
+                let cpi_program = token_program.to_account_info();
+                let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, stake_signer);
+                token::burn(cpi_ctx, burn_amount)?;
             }
 
             //Log the result
@@ -164,9 +694,20 @@ impl PlayGame<'_> {
             );
 
             // update burn amount on game vault and player profile
-            game_vault.burned_amount += converted_stake_amount;
+            // The profile counts the whole lost stake, the jackpot share is gone for the player too
+            game_vault.burned_amount += burn_amount;
+            game_vault.jackpot_amount += jackpot_contribution;
+            game_vault.jackpot_contributed += jackpot_contribution;
             player_profile.num_games += 1;
             player_profile.tokens_burned += converted_stake_amount;
+            player_profile.record_expected_payout(payout_value, game_vault.num_splits, 0);
+            game_vault.anomaly_stats.record_matchup(
+                converted_stake_amount,
+                payout_value,
+                num_splits,
+                0,
+                converted_stake_amount,
+            );
             player_profile.update_average_num_splits(game_vault.num_splits);
             player_profile.update_average_stake_amount(stake_amount);
 
@@ -174,21 +715,40 @@ impl PlayGame<'_> {
             // Do this last to not bias the average number of splits
             game_vault.add_entry(choice);
 
-            return Ok(());
+            return Ok(GameResult {
+                stake_amount: converted_stake_amount,
+                player_choice: choice,
+                opponent_choice,
+                outcome_amount: 0,
+                burned_amount: burn_amount,
+                jackpot_contribution,
+                jackpot_payout: 0,
+                referral_payout: 0,
+                integrator_payout: 0,
+                got_split_bonus: false,
+                passes_burn_rate_check,
+                passes_winnings_check,
+                passes_sprt_check,
+            });
         }
 
         // Logic continues if opponent didn't choose Steal
+        let mut got_split_bonus = false;
         let transfer_amount = match choice {
             Choice::Split => {
                 // Calculate the split value
-                let mut split_value = game_vault.calculate_split_value(converted_stake_amount);
-                let can_get_split_bonus = player_profile.can_get_split_bonus();
+                let mut split_value = payout_value;
+                let can_get_split_bonus =
+                    player_profile.can_get_split_bonus(config.split_bonus_confidence_bps());
                 if can_get_split_bonus {
                     // Check if they got the split bonus
                     // The split bonus check should happen with 1/num_splits probability,
                     // this results in a constant 1/1000 split bonus prob whenever choosing split
                     let split_bonus_check: u16 = rng.gen_range(0..game_vault.num_splits);
-                    let got_split_bonus = split_bonus_check == 0;
+                    got_split_bonus = split_bonus_check == 0;
+                    game_vault
+                        .anomaly_stats
+                        .record_split_bonus_chance(num_splits, got_split_bonus);
                     if got_split_bonus {
                         let split_bonus =
                             game_vault.calculate_split_bonus(converted_stake_amount, split_value);
@@ -207,11 +767,26 @@ impl PlayGame<'_> {
             }
             Choice::Steal => {
                 // Calculate the steal value
-                let steal_value = game_vault.calculate_steal_value(converted_stake_amount);
+                let steal_value = payout_value;
                 steal_value.min(game_vault.mint_remaining)
             }
         };
 
+        // The referrer's share comes out of the winnings, so referring a second wallet of your own gains nothing
+        let referral_payout = if player_profile.referrer == Pubkey::default() {
+            0
+        } else {
+            apply_basis_points(transfer_amount, config.referral_payout_bps)
+        };
+        // Same for the integrator, which gets its share of what's left
+        let integrator_payout = integrator.as_ref().map_or(0, |integrator| {
+            apply_basis_points(
+                transfer_amount - referral_payout,
+                integrator.revenue_share_bps,
+            )
+        });
+        let player_amount = transfer_amount - referral_payout - integrator_payout;
+
         let seeds = &[b"lord of the gourd".as_ref(), &[game_vault.bump]];
         let signer = &[&seeds[..]];
 
@@ -221,68 +796,285 @@ impl PlayGame<'_> {
             to: player_token_account.to_account_info(),
             authority: game_vault.to_account_info(),
         };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_program = token_program.to_account_info();
         let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
-        token::transfer(cpi_ctx, transfer_amount)?;
+        token::transfer(cpi_ctx, player_amount)?;
 
         // Log the result
         Self::log_game_result(
             converted_stake_amount,
             choice,
             opponent_choice,
-            transfer_amount,
+            player_amount,
             game_vault.mint_remaining,
         );
         // Update the game vault's mint remaining and player's tokens gained
+        // The luck checks count the whole payout, the referrer's and integrator's shares included
         game_vault.mint_remaining -= transfer_amount;
         player_profile.num_games += 1;
         player_profile.tokens_gained += transfer_amount;
+        player_profile.record_expected_payout(
+            payout_value,
+            game_vault.num_splits,
+            transfer_amount.min(payout_value),
+        );
+        game_vault.anomaly_stats.record_matchup(
+            converted_stake_amount,
+            payout_value,
+            num_splits,
+            transfer_amount.min(payout_value),
+            0,
+        );
         player_profile.update_average_num_splits(game_vault.num_splits);
         player_profile.update_average_stake_amount(stake_amount);
 
         // Add entry has to happen after calc value so it doesn't mess with internal values
         // Has to happen last so it doesn't bias the average number of splits
         game_vault.add_entry(choice);
+        Ok(GameResult {
+            stake_amount: converted_stake_amount,
+            player_choice: choice,
+            opponent_choice,
+            outcome_amount: player_amount,
+            burned_amount: 0,
+            jackpot_contribution: 0,
+            jackpot_payout: 0,
+            referral_payout,
+            integrator_payout,
+            got_split_bonus,
+            passes_burn_rate_check,
+            passes_winnings_check,
+            passes_sprt_check,
+        })
+    }
+
+    // The payout was already taken out of the player's winnings and the vault's mint remaining
+    fn pay_referrer(&mut self, referral_payout: u64) -> Result<()> {
+        let PlayGame {
+            player_profile,
+            game_vault,
+            game_vault_token_account,
+            referrer_profile,
+            referrer_token_account,
+            token_program,
+            ..
+        } = self;
+
+        if referral_payout == 0 {
+            return Ok(());
+        }
+
+        let referrer_profile = referrer_profile
+            .as_mut()
+            .ok_or(SplitOrStealError::ReferrerAccountMissing)?;
+        let referrer_token_account = referrer_token_account
+            .as_ref()
+            .ok_or(SplitOrStealError::ReferrerAccountMissing)?;
+        Self::check_referrer_profile(
+            &player_profile.referrer,
+            referrer_profile,
+            &game_vault.key(),
+        )?;
+        require_keys_eq!(
+            referrer_token_account.owner,
+            player_profile.referrer,
+            SplitOrStealError::InvalidReferrer
+        );
+
+        let seeds = &[b"lord of the gourd".as_ref(), &[game_vault.bump]];
+        let signer = &[&seeds[..]];
+
+        // Transfer the referrer's share of the player's payout
+        let cpi_accounts = Transfer {
+            from: game_vault_token_account.to_account_info(),
+            to: referrer_token_account.to_account_info(),
+            authority: game_vault.to_account_info(),
+        };
+        let cpi_program = token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::transfer(cpi_ctx, referral_payout)?;
+
+        referrer_profile.referral_tokens_earned += referral_payout;
+        Ok(())
+    }
+
+    // Like the referral payout, already taken out of the player's winnings and the vault's mint remaining
+    fn pay_integrator(&mut self, integrator_payout: u64) -> Result<()> {
+        let PlayGame {
+            game_vault,
+            game_vault_token_account,
+            integrator,
+            integrator_token_account,
+            token_program,
+            ..
+        } = self;
+
+        // Only there when played through play_game_cpi, already checked in begin_play
+        let (Some(integrator), Some(integrator_token_account)) =
+            (integrator, integrator_token_account)
+        else {
+            return Ok(());
+        };
+        if integrator_payout != 0 {
+            let seeds = &[b"lord of the gourd".as_ref(), &[game_vault.bump]];
+            let signer = &[&seeds[..]];
+
+            let cpi_accounts = Transfer {
+                from: game_vault_token_account.to_account_info(),
+                to: integrator_token_account.to_account_info(),
+                authority: game_vault.to_account_info(),
+            };
+            let cpi_program = token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+            token::transfer(cpi_ctx, integrator_payout)?;
+        }
+        integrator.record_game(integrator_payout);
         Ok(())
     }
 
+    fn draw_jackpot(&mut self, rng: &mut ChaCha8Rng) -> Result<u64> {
+        let PlayGame {
+            player_token_account,
+            game_vault,
+            jackpot_token_account,
+            token_program,
+            ..
+        } = self;
+
+        let jackpot_token_account = match jackpot_token_account {
+            Some(jackpot_token_account) => jackpot_token_account,
+            None => return Ok(0),
+        };
+        if game_vault.config.jackpot_odds == 0 {
+            return Ok(0);
+        }
+
+        // Draw even when the pool is empty so the odds never depend on the pool size
+        let jackpot_check: u32 = rng.gen_range(0..game_vault.config.jackpot_odds);
+        if jackpot_check != 0 || game_vault.jackpot_amount == 0 {
+            return Ok(0);
+        }
+
+        let jackpot_payout = game_vault.jackpot_amount;
+        let seeds = &[b"lord of the gourd".as_ref(), &[game_vault.bump]];
+        let signer = &[&seeds[..]];
+
+        // Pay out the whole pool to the player
+        let cpi_accounts = Transfer {
+            from: jackpot_token_account.to_account_info(),
+            to: player_token_account.to_account_info(),
+            authority: game_vault.to_account_info(),
+        };
+        let cpi_program = token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::transfer(cpi_ctx, jackpot_payout)?;
+
+        msg!("Won the jackpot! Jackpot Amount: {}", jackpot_payout);
+        game_vault.jackpot_amount = 0;
+        game_vault.jackpot_paid_out += jackpot_payout;
+        game_vault.num_jackpots += 1;
+        Ok(jackpot_payout)
+    }
+
+    // Returns the program expected at the top level, us or the integrator calling us
+    fn check_stack_height(&self, program_id: &Pubkey, via_cpi: bool) -> Result<Pubkey> {
+        let stack_height = get_stack_height();
+        if !via_cpi {
+            require!(
+                stack_height == TRANSACTION_LEVEL_STACK_HEIGHT && self.integrator.is_none(),
+                SplitOrStealError::UnsanctionedCpi
+            );
+            return Ok(*program_id);
+        }
+
+        // Only one level deep so the integrator's own instruction is the top level one we check
+        require!(
+            stack_height == TRANSACTION_LEVEL_STACK_HEIGHT + 1,
+            SplitOrStealError::NotCalledViaCpi
+        );
+        let (Some(integrator), Some(integrator_authority), Some(integrator_token_account)) = (
+            self.integrator.as_ref(),
+            self.integrator_authority.as_ref(),
+            self.integrator_token_account.as_ref(),
+        ) else {
+            return err!(SplitOrStealError::IntegratorAccountMissing);
+        };
+        require!(integrator.enabled, SplitOrStealError::IntegratorDisabled);
+        require_keys_eq!(
+            integrator_authority.key(),
+            integrator.authority,
+            SplitOrStealError::InvalidIntegratorAuthority
+        );
+        require_keys_eq!(
+            integrator_token_account.key(),
+            integrator.revenue_token_account,
+            SplitOrStealError::InvalidIntegratorAuthority
+        );
+        Ok(integrator.program_id)
+    }
+
     fn check_top_level_instruction(
         instructions_sysvar: &AccountInfo,
         program_id: &Pubkey,
+        top_level_program_id: &Pubkey,
+        instruction_allowlist: Option<&InstructionAllowlist>,
     ) -> Result<()> {
         // Load the current instruction index
         let current_index = load_current_index_checked(instructions_sysvar)?;
 
-        // Ensure the current instruction is for the active program
+        // Ensure the current instruction is for the active program, or the integrator when called through CPI
         let current_instruction =
             load_instruction_at_checked(current_index as usize, instructions_sysvar)?;
         require_keys_eq!(
             current_instruction.program_id,
-            *program_id,
+            *top_level_program_id,
             SplitOrStealError::InvalidActiveProgram
         );
 
-        // If it's not the first, ensure all previous instructions are compute budget program
+        // If it's not the first, ensure all previous instructions are compute budget program or allowlisted
         for i in 0..current_index {
             let instruction = load_instruction_at_checked(i as usize, instructions_sysvar)?;
-            require_keys_eq!(
-                instruction.program_id,
-                COMPUTE_BUDGET_PROGRAM_ID,
-                SplitOrStealError::InvalidComputeBudgetProgramId
-            );
+            if instruction.program_id == COMPUTE_BUDGET_PROGRAM_ID {
+                continue;
+            }
+            // Durable nonce transactions have to start with advancing the nonce, so it's only allowed first
+            if i == 0 && Self::is_advance_nonce_instruction(&instruction) {
+                continue;
+            }
+            match instruction_allowlist {
+                Some(allowlist) => allowlist.check_companion(
+                    &instruction.program_id,
+                    InstructionPosition::Before,
+                    program_id,
+                )?,
+                None => return err!(SplitOrStealError::InvalidComputeBudgetProgramId),
+            }
         }
 
-        // Ensure there are no instructions after the current instruction
-        let next_instruction =
-            load_instruction_at_checked((current_index + 1) as usize, instructions_sysvar);
-        require!(
-            next_instruction.is_err(),
-            SplitOrStealError::UnexpectedInstruction
-        );
+        // Ensure there are no instructions after the current instruction, unless allowlisted
+        let mut next_index = current_index as usize + 1;
+        while let Ok(instruction) = load_instruction_at_checked(next_index, instructions_sysvar) {
+            match instruction_allowlist {
+                Some(allowlist) => allowlist.check_companion(
+                    &instruction.program_id,
+                    InstructionPosition::After,
+                    program_id,
+                )?,
+                None => return err!(SplitOrStealError::UnexpectedInstruction),
+            }
+            next_index += 1;
+        }
 
         Ok(())
     }
 
+    fn is_advance_nonce_instruction(instruction: &Instruction) -> bool {
+        instruction.program_id == system_program::ID
+            && instruction.data.len() == 4
+            && u32::from_le_bytes(instruction.data[..4].try_into().unwrap())
+                == ADVANCE_NONCE_ACCOUNT_DISCRIMINANT
+    }
+
     fn log_game_result(
         stake_amount: u64,
         player_choice: Choice,
@@ -0,0 +1,17 @@
+use super::*;
+
+impl PlayGame<'_> {
+    // Same game as play_game, but only callable through CPI from an enabled integrator program.
+    // Returns nothing so the integrator can't just read the outcome and revert a loss
+    pub fn handle_cpi(ctx: Context<Self>, choice: Choice, stake_amount: u16) -> Result<()> {
+        ctx.accounts
+            .begin_play(ctx.program_id, ctx.bumps.player_profile, true)?;
+
+        ctx.accounts.check_stakes(&[stake_amount])?;
+        ctx.accounts.check_cooldown(1)?;
+
+        let mut rng = ctx.accounts.seed_rng()?;
+        ctx.accounts.play_round(choice, stake_amount, &mut rng)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use super::*;
+
+#[derive(Accounts)]
+#[instruction(capacity: u16)]
+pub struct ResizePlayerHistory<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    #[account(
+        seeds = [b"lord of the gourd"],
+        bump = game_vault.bump
+    )]
+    pub game_vault: Box<Account<'info, GameVault>>,
+    #[account(
+        init_if_needed,
+        payer = player,
+        seeds = [b"history", game_vault.key().as_ref(), player.key().as_ref()],
+        bump,
+        space = PlayerHistory::space(capacity)
+    )]
+    pub player_history: Box<Account<'info, PlayerHistory>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl ResizePlayerHistory<'_> {
+    pub fn handle(ctx: Context<Self>, capacity: u16) -> Result<()> {
+        let player_history = &mut ctx.accounts.player_history;
+        player_history.bump = ctx.bumps.player_history;
+        player_history.player = ctx.accounts.player.key();
+        player_history.resize(capacity)?;
+
+        // init_if_needed only sizes new accounts, an existing history is grown or shrunk here
+        let history_info = player_history.to_account_info();
+        let new_space = PlayerHistory::space(capacity);
+        let current_space = history_info.data_len();
+        if new_space != current_space {
+            let rent = Rent::get()?;
+            let new_minimum = rent.minimum_balance(new_space);
+            let lamports = history_info.lamports();
+            if new_minimum > lamports {
+                let cpi_accounts = system_program::Transfer {
+                    from: ctx.accounts.player.to_account_info(),
+                    to: history_info.clone(),
+                };
+                let cpi_context =
+                    CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+                system_program::transfer(cpi_context, new_minimum - lamports)?;
+            } else {
+                // Rent freed up by shrinking goes back to the player
+                **history_info.try_borrow_mut_lamports()? -= lamports - new_minimum;
+                **ctx.accounts.player.try_borrow_mut_lamports()? += lamports - new_minimum;
+            }
+            history_info.realloc(new_space, false)?;
+        }
+
+        msg!("Player history holds the last {} games", capacity);
+        Ok(())
+    }
+}
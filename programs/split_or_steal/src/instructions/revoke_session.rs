@@ -0,0 +1,44 @@
+use super::*;
+
+#[derive(Accounts)]
+pub struct RevokeSession<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    #[account(
+        mut,
+        close = player,
+        has_one = player,
+        seeds = [b"session", player.key().as_ref(), session.session_key.as_ref()],
+        bump = session.bump
+    )]
+    pub session: Box<Account<'info, PlaySession>>,
+    #[account(
+        mut,
+        token::authority = player
+    )]
+    pub player_token_account: Box<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl RevokeSession<'_> {
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let RevokeSession {
+            player,
+            session,
+            player_token_account,
+            token_program,
+        } = ctx.accounts;
+
+        // Only clear the delegate if it's still this session, the player may have approved something else since
+        if player_token_account.delegate == COption::Some(session.key()) {
+            let cpi_accounts = Revoke {
+                source: player_token_account.to_account_info(),
+                authority: player.to_account_info(),
+            };
+            let cpi_program = token_program.to_account_info();
+            let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+            token::revoke(cpi_ctx)?;
+        }
+        Ok(())
+    }
+}
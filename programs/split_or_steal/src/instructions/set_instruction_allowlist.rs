@@ -0,0 +1,32 @@
+use super::*;
+
+#[derive(Accounts)]
+pub struct SetInstructionAllowlist<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        seeds = [b"lord of the gourd"],
+        bump = game_vault.bump,
+        has_one = creator
+    )]
+    pub game_vault: Box<Account<'info, GameVault>>,
+    #[account(
+        init_if_needed,
+        payer = creator,
+        seeds = [b"allowlist", game_vault.key().as_ref()],
+        bump,
+        space = 8 + std::mem::size_of::<InstructionAllowlist>()
+    )]
+    pub instruction_allowlist: Box<Account<'info, InstructionAllowlist>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl SetInstructionAllowlist<'_> {
+    pub fn handle(ctx: Context<Self>, programs: Vec<AllowedProgram>) -> Result<()> {
+        let instruction_allowlist = &mut ctx.accounts.instruction_allowlist;
+        instruction_allowlist.bump = ctx.bumps.instruction_allowlist;
+        instruction_allowlist.set_programs(&programs, ctx.program_id)?;
+        msg!("Allowing {} companion programs", programs.len());
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use super::*;
+
+#[derive(Accounts)]
+#[instruction(program_id: Pubkey)]
+pub struct SetIntegrator<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        seeds = [b"lord of the gourd"],
+        bump = game_vault.bump,
+        has_one = creator
+    )]
+    pub game_vault: Box<Account<'info, GameVault>>,
+    #[account(
+        init_if_needed,
+        payer = creator,
+        seeds = [b"integrator", game_vault.key().as_ref(), program_id.as_ref()],
+        bump,
+        space = 8 + std::mem::size_of::<Integrator>()
+    )]
+    pub integrator: Box<Account<'info, Integrator>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl SetIntegrator<'_> {
+    // Setting enabled to false is how an integrator gets cut off, its stats are kept.
+    // An integrator always sees its own token balances after the CPI and can revert any game it lost,
+    // only enable programs that are non-upgradeable and audited not to do that
+    pub fn handle(
+        ctx: Context<Self>,
+        program_id: Pubkey,
+        revenue_token_account: Pubkey,
+        revenue_share_bps: u16,
+        enabled: bool,
+    ) -> Result<()> {
+        require_keys_neq!(
+            program_id,
+            *ctx.program_id,
+            SplitOrStealError::CannotAllowGameProgram
+        );
+        ctx.accounts.integrator.configure(
+            ctx.bumps.integrator,
+            program_id,
+            revenue_token_account,
+            revenue_share_bps,
+            enabled,
+        )?;
+        msg!(
+            "Integrator {} enabled: {}, revenue share: {} bps",
+            program_id,
+            enabled,
+            revenue_share_bps
+        );
+        Ok(())
+    }
+}
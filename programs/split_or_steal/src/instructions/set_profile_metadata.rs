@@ -0,0 +1,93 @@
+use super::*;
+
+#[derive(Accounts)]
+#[instruction(nickname: String)]
+pub struct SetProfileMetadata<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    #[account(
+        seeds = [b"lord of the gourd"],
+        bump = game_vault.bump
+    )]
+    pub game_vault: Box<Account<'info, GameVault>>,
+    // Only existing players get a public profile
+    #[account(
+        seeds = [b"profile", game_vault.key().as_ref(), player.key().as_ref()],
+        bump = player_profile.bump
+    )]
+    pub player_profile: Box<Account<'info, PlayerProfile>>,
+    // Not seeded by vault on purpose, the name and avatar follow the wallet to every vault it plays in
+    #[account(
+        init_if_needed,
+        payer = player,
+        seeds = [b"metadata", player.key().as_ref()],
+        bump,
+        space = 8 + std::mem::size_of::<ProfileMetadata>()
+    )]
+    pub profile_metadata: Box<Account<'info, ProfileMetadata>>,
+    #[account(
+        init_if_needed,
+        payer = player,
+        seeds = [b"nickname", normalize_nickname(&nickname).as_ref()],
+        bump,
+        space = 8 + std::mem::size_of::<NicknameRecord>()
+    )]
+    pub nickname_record: Box<Account<'info, NicknameRecord>>,
+    // Needed when changing nickname, the old one is freed up and its rent returned
+    #[account(mut, close = player)]
+    pub previous_nickname_record: Option<Box<Account<'info, NicknameRecord>>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl SetProfileMetadata<'_> {
+    pub fn handle(
+        ctx: Context<Self>,
+        nickname: String,
+        avatar_uri: String,
+        show_on_leaderboard: bool,
+    ) -> Result<()> {
+        let SetProfileMetadata {
+            player,
+            profile_metadata,
+            nickname_record,
+            previous_nickname_record,
+            ..
+        } = ctx.accounts;
+
+        // A fresh record has the default player
+        require!(
+            nickname_record.player == Pubkey::default() || nickname_record.player == player.key(),
+            SplitOrStealError::NicknameTaken
+        );
+
+        let previous_normalized = profile_metadata.normalized_nickname();
+        let new_normalized = normalize_nickname(&nickname);
+        if profile_metadata.has_nickname() && previous_normalized != new_normalized {
+            let previous_nickname_record = previous_nickname_record
+                .as_ref()
+                .ok_or(SplitOrStealError::PreviousNicknameRecordMissing)?;
+            require!(
+                previous_nickname_record.player == player.key()
+                    && previous_nickname_record.nickname == previous_normalized,
+                SplitOrStealError::InvalidPreviousNicknameRecord
+            );
+        } else {
+            // Nothing to free, and closing the record being kept would give the nickname away
+            require!(
+                previous_nickname_record.is_none(),
+                SplitOrStealError::InvalidPreviousNicknameRecord
+            );
+        }
+
+        profile_metadata.set(&nickname, &avatar_uri, show_on_leaderboard)?;
+        profile_metadata.bump = ctx.bumps.profile_metadata;
+        profile_metadata.player = player.key();
+
+        nickname_record.bump = ctx.bumps.nickname_record;
+        nickname_record.player = player.key();
+        nickname_record.nickname = new_normalized;
+
+        msg!("Set nickname to {}", nickname);
+        Ok(())
+    }
+}
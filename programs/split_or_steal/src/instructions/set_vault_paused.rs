@@ -0,0 +1,28 @@
+use super::*;
+
+#[derive(Accounts)]
+pub struct SetVaultPaused<'info> {
+    pub creator: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"lord of the gourd"],
+        bump = game_vault.bump,
+        has_one = creator
+    )]
+    pub game_vault: Box<Account<'info, GameVault>>,
+}
+
+impl SetVaultPaused<'_> {
+    // Resetting the stats stops an anomaly from before the unpause tripping the auto pause straight away again
+    pub fn handle(ctx: Context<Self>, paused: bool, reset_anomaly_stats: bool) -> Result<()> {
+        msg!(
+            "Setting vault paused: {}, resetting anomaly stats: {}",
+            paused,
+            reset_anomaly_stats
+        );
+        ctx.accounts
+            .game_vault
+            .set_paused(paused, reset_anomaly_stats);
+        Ok(())
+    }
+}
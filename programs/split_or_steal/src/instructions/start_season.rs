@@ -0,0 +1,41 @@
+use super::*;
+
+#[derive(Accounts)]
+pub struct StartSeason<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"lord of the gourd"],
+        bump = game_vault.bump,
+        has_one = creator
+    )]
+    pub game_vault: Box<Account<'info, GameVault>>,
+    // Archive of the season that is ending
+    #[account(
+        init,
+        payer = creator,
+        seeds = [b"season", game_vault.key().as_ref(), &game_vault.season.to_le_bytes()],
+        bump,
+        space = 8 + std::mem::size_of::<SeasonArchive>()
+    )]
+    pub season_archive: Box<Account<'info, SeasonArchive>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl StartSeason<'_> {
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let StartSeason {
+            game_vault,
+            season_archive,
+            ..
+        } = ctx.accounts;
+
+        let current_slot = Clock::get()?.slot;
+        season_archive.initialize(game_vault, ctx.bumps.season_archive, current_slot);
+        game_vault.start_new_season(current_slot);
+
+        msg!("Started season {}", game_vault.season);
+        Ok(())
+    }
+}
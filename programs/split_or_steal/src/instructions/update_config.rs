@@ -0,0 +1,27 @@
+use super::*;
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub creator: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"lord of the gourd"],
+        bump = game_vault.bump,
+        has_one = creator
+    )]
+    pub game_vault: Box<Account<'info, GameVault>>,
+}
+
+impl UpdateConfig<'_> {
+    pub fn handle(ctx: Context<Self>, config: VaultConfig) -> Result<()> {
+        config.validate()?;
+        // Every play pays into the jackpot account once it's enabled, so it has to exist first
+        require!(
+            !config.jackpot_enabled() || ctx.accounts.game_vault.jackpot_bump != 0,
+            SplitOrStealError::JackpotNotInitialized
+        );
+        msg!("Updating vault config: {:?}", config);
+        ctx.accounts.game_vault.config = config;
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use super::*;
+
+// Anyone can push a team's latest score onto the leaderboard
+#[derive(Accounts)]
+pub struct UpdateTeamLeaderboard<'info> {
+    #[account(
+        seeds = [b"lord of the gourd"],
+        bump = game_vault.bump
+    )]
+    pub game_vault: Box<Account<'info, GameVault>>,
+    #[account(
+        mut,
+        seeds = [b"team leaderboard", game_vault.key().as_ref()],
+        bump = team_leaderboard.bump
+    )]
+    pub team_leaderboard: Box<Account<'info, TeamLeaderboard>>,
+    pub team: Box<Account<'info, Team>>,
+}
+
+impl UpdateTeamLeaderboard<'_> {
+    pub fn handle(ctx: Context<Self>) -> Result<()> {
+        let UpdateTeamLeaderboard {
+            team_leaderboard,
+            team,
+            ..
+        } = ctx.accounts;
+        team_leaderboard.update(team.key(), team.score());
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use super::*;
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    pub creator: Signer<'info>,
+    #[account(
+        seeds = [b"lord of the gourd"],
+        bump = game_vault.bump,
+        has_one = creator
+    )]
+    pub game_vault: Box<Account<'info, GameVault>>,
+    #[account(
+        mut,
+        seeds = [b"treasury", game_vault.key().as_ref()],
+        bump = treasury.bump
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+    #[account(mut)]
+    pub recipient: SystemAccount<'info>,
+}
+
+impl WithdrawTreasury<'_> {
+    pub fn handle(ctx: Context<Self>, amount: u64) -> Result<()> {
+        let treasury_info = ctx.accounts.treasury.to_account_info();
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(treasury_info.data_len());
+        require_gte!(
+            Treasury::withdrawable(treasury_info.lamports(), rent_exempt_minimum),
+            amount,
+            SplitOrStealError::InsufficientTreasuryBalance
+        );
+
+        // The program owns the treasury so it can move the lamports directly
+        **treasury_info.try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.recipient.try_borrow_mut_lamports()? += amount;
+
+        ctx.accounts.treasury.total_withdrawn += amount;
+        msg!(
+            "Withdrew {} lamports from the treasury to {}",
+            amount,
+            ctx.accounts.recipient.key()
+        );
+        Ok(())
+    }
+}
@@ -1,24 +1,40 @@
 use anchor_lang::prelude::Pubkey;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::instruction::{
+    get_stack_height, Instruction, TRANSACTION_LEVEL_STACK_HEIGHT,
+};
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::solana_program::program_option::COption;
 use anchor_lang::solana_program::pubkey;
 use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
 use anchor_lang::solana_program::sysvar::slot_hashes;
 use anchor_lang::system_program;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
-use anchor_spl::{associated_token::AssociatedToken, token::Burn};
+use anchor_spl::token::{self, Approve, Mint, Revoke, Token, TokenAccount, Transfer};
+use anchor_spl::token_2022::{self, Token2022};
+use anchor_spl::{
+    associated_token::{self, AssociatedToken},
+    token::Burn,
+};
+use spl_token_2022::extension::{
+    group_member_pointer, group_pointer, metadata_pointer, ExtensionType,
+};
+use spl_token_2022::state::Mint as Mint2022;
+use spl_token_metadata_interface::state::TokenMetadata;
 use sysvar_instructions::{load_current_index_checked, load_instruction_at_checked};
 
 use rand::Rng;
 use rand_chacha::{rand_core::SeedableRng, ChaCha8Rng};
 
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod state;
 
 pub use crate::instructions::*;
 pub use crate::state::*;
 pub use error::SplitOrStealError;
+pub use events::*;
 
 #[cfg(not(feature = "no-entrypoint"))]
 use solana_security_txt::security_txt;
@@ -48,6 +64,145 @@ pub mod split_or_steal {
     pub fn play_game(ctx: Context<PlayGame>, choice: Choice, stake_amount: u16) -> Result<()> {
         PlayGame::handle(ctx, choice, stake_amount)
     }
+
+    pub fn play_batch(ctx: Context<PlayGame>, games: Vec<BatchGame>) -> Result<Vec<GameResult>> {
+        PlayGame::handle_batch(ctx, games)
+    }
+
+    pub fn play_game_cpi(ctx: Context<PlayGame>, choice: Choice, stake_amount: u16) -> Result<()> {
+        PlayGame::handle_cpi(ctx, choice, stake_amount)
+    }
+
+    pub fn update_config(ctx: Context<UpdateConfig>, config: VaultConfig) -> Result<()> {
+        UpdateConfig::handle(ctx, config)
+    }
+
+    pub fn migrate_vault(ctx: Context<MigrateVault>) -> Result<()> {
+        MigrateVault::handle(ctx)
+    }
+
+    pub fn set_integrator(
+        ctx: Context<SetIntegrator>,
+        program_id: Pubkey,
+        revenue_token_account: Pubkey,
+        revenue_share_bps: u16,
+        enabled: bool,
+    ) -> Result<()> {
+        SetIntegrator::handle(
+            ctx,
+            program_id,
+            revenue_token_account,
+            revenue_share_bps,
+            enabled,
+        )
+    }
+
+    pub fn set_vault_paused(
+        ctx: Context<SetVaultPaused>,
+        paused: bool,
+        reset_anomaly_stats: bool,
+    ) -> Result<()> {
+        SetVaultPaused::handle(ctx, paused, reset_anomaly_stats)
+    }
+
+    pub fn initialize_jackpot(ctx: Context<InitializeJackpot>) -> Result<()> {
+        InitializeJackpot::handle(ctx)
+    }
+
+    pub fn initialize_treasury(ctx: Context<InitializeTreasury>) -> Result<()> {
+        InitializeTreasury::handle(ctx)
+    }
+
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        WithdrawTreasury::handle(ctx, amount)
+    }
+
+    pub fn start_season(ctx: Context<StartSeason>) -> Result<()> {
+        StartSeason::handle(ctx)
+    }
+
+    pub fn create_team(ctx: Context<CreateTeam>, name: String, max_members: u16) -> Result<()> {
+        CreateTeam::handle(ctx, name, max_members)
+    }
+
+    pub fn join_team(ctx: Context<JoinTeam>) -> Result<()> {
+        JoinTeam::handle(ctx)
+    }
+
+    pub fn leave_team(ctx: Context<LeaveTeam>) -> Result<()> {
+        LeaveTeam::handle(ctx)
+    }
+
+    pub fn migrate_profile(ctx: Context<MigrateProfile>) -> Result<()> {
+        MigrateProfile::handle(ctx)
+    }
+
+    pub fn link_wallet(ctx: Context<LinkWallet>) -> Result<()> {
+        LinkWallet::handle(ctx)
+    }
+
+    pub fn initialize_team_leaderboard(ctx: Context<InitializeTeamLeaderboard>) -> Result<()> {
+        InitializeTeamLeaderboard::handle(ctx)
+    }
+
+    pub fn update_team_leaderboard(ctx: Context<UpdateTeamLeaderboard>) -> Result<()> {
+        UpdateTeamLeaderboard::handle(ctx)
+    }
+
+    pub fn set_instruction_allowlist(
+        ctx: Context<SetInstructionAllowlist>,
+        programs: Vec<AllowedProgram>,
+    ) -> Result<()> {
+        SetInstructionAllowlist::handle(ctx, programs)
+    }
+
+    pub fn initialize_achievement_collection(
+        ctx: Context<InitializeAchievementCollection>,
+    ) -> Result<()> {
+        InitializeAchievementCollection::handle(ctx)
+    }
+
+    pub fn claim_achievement(
+        ctx: Context<ClaimAchievement>,
+        achievement: Achievement,
+    ) -> Result<()> {
+        ClaimAchievement::handle(ctx, achievement)
+    }
+
+    pub fn claim_deposit(ctx: Context<ClaimDeposit>) -> Result<()> {
+        ClaimDeposit::handle(ctx)
+    }
+
+    pub fn close_profile(ctx: Context<CloseProfile>) -> Result<()> {
+        CloseProfile::handle(ctx)
+    }
+
+    pub fn create_session(
+        ctx: Context<CreateSession>,
+        session_key: Pubkey,
+        expiry_slot: u64,
+        max_total_stake: u64,
+        max_games: u32,
+    ) -> Result<()> {
+        CreateSession::handle(ctx, session_key, expiry_slot, max_total_stake, max_games)
+    }
+
+    pub fn set_profile_metadata(
+        ctx: Context<SetProfileMetadata>,
+        nickname: String,
+        avatar_uri: String,
+        show_on_leaderboard: bool,
+    ) -> Result<()> {
+        SetProfileMetadata::handle(ctx, nickname, avatar_uri, show_on_leaderboard)
+    }
+
+    pub fn resize_player_history(ctx: Context<ResizePlayerHistory>, capacity: u16) -> Result<()> {
+        ResizePlayerHistory::handle(ctx, capacity)
+    }
+
+    pub fn revoke_session(ctx: Context<RevokeSession>) -> Result<()> {
+        RevokeSession::handle(ctx)
+    }
 }
 
 #[derive(Accounts)]
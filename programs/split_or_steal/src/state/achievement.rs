@@ -0,0 +1,43 @@
+use super::*;
+
+pub const ACHIEVEMENT_NUM_GAMES: u32 = 1_000;
+pub const ACHIEVEMENT_SPLIT_STREAK: u32 = 10;
+// Winning while the ring buffer is at least 90% steals
+pub const ACHIEVEMENT_STEAL_HEAVY_NUM_SPLITS: u16 = 100;
+pub const ACHIEVEMENT_SYMBOL: &str = "SORS";
+pub const ACHIEVEMENT_COLLECTION_NAME: &str = "Split or Steal Achievements";
+// Badges share the token's artwork
+pub const ACHIEVEMENT_URI: &str =
+    "https://bafybeibscf4nobkyhgkzwyl7pcfeozxackrmupvgdut5ef3y4unae5yray.ipfs.nftstorage.link";
+
+// Each one is a bit in the profile's achievement flags, so only ever add to the end
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Achievement {
+    FirstSplitBonus = 0,
+    ThousandGames = 1,
+    TenSplitsInARow = 2,
+    SurvivedStealHeavyVault = 3,
+}
+
+impl Achievement {
+    pub const ALL: [Achievement; 4] = [
+        Achievement::FirstSplitBonus,
+        Achievement::ThousandGames,
+        Achievement::TenSplitsInARow,
+        Achievement::SurvivedStealHeavyVault,
+    ];
+
+    pub fn flag(self) -> u32 {
+        1 << self as u8
+    }
+
+    // Badge name in the mint's metadata
+    pub fn name(self) -> &'static str {
+        match self {
+            Achievement::FirstSplitBonus => "Split or Steal: First Split Bonus",
+            Achievement::ThousandGames => "Split or Steal: Thousand Games",
+            Achievement::TenSplitsInARow => "Split or Steal: Ten Splits in a Row",
+            Achievement::SurvivedStealHeavyVault => "Split or Steal: Survived a Steal Heavy Vault",
+        }
+    }
+}
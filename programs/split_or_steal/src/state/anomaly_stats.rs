@@ -0,0 +1,156 @@
+use num::integer::Roots;
+
+use super::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Anomaly {
+    Payouts,
+    Burns,
+    SplitBonuses,
+}
+
+// Expected vs actual across every profile, so luck spread over lots of sybil profiles still adds up somewhere.
+// Variances are in base units squared, split bonus counts are scaled by 1e9 and their variance by 1e18.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct AnomalyStats {
+    pub num_games: u64,
+    // Payouts leave out split bonuses, those are tracked on their own below
+    pub expected_payout: u64,
+    pub actual_payout: u64,
+    pub payout_variance: u128,
+    pub expected_burn: u64,
+    pub actual_burn: u64,
+    pub burn_variance: u128,
+    pub expected_split_bonuses: u64,
+    pub num_split_bonuses: u64,
+    pub split_bonus_variance: u128,
+}
+
+impl AnomalyStats {
+    // Opponent splits num_splits / 1000 of the time, paying out payout_value, otherwise the stake is lost
+    pub fn record_matchup(
+        &mut self,
+        stake_amount: u64,
+        payout_value: u64,
+        num_splits: u16,
+        actual_payout: u64,
+        actual_burn: u64,
+    ) {
+        let stake = stake_amount as u128;
+        let value = payout_value as u128;
+        let num_splits = num_splits as u128;
+        let num_steals = 1_000 - num_splits;
+
+        self.num_games += 1;
+        self.expected_payout += (value * num_splits / 1_000) as u64;
+        self.payout_variance += value * value * num_splits * num_steals / 1_000_000;
+        self.actual_payout += actual_payout;
+        self.expected_burn += (stake * num_steals / 1_000) as u64;
+        self.burn_variance += stake * stake * num_splits * num_steals / 1_000_000;
+        self.actual_burn += actual_burn;
+    }
+
+    // Only called when the player was eligible and won a split, then the bonus comes up 1 in num_splits
+    pub fn record_split_bonus_chance(&mut self, num_splits: u16, got_split_bonus: bool) {
+        let probability = FIXED_SCALE as u128 / num_splits as u128;
+        self.expected_split_bonuses += probability as u64;
+        self.split_bonus_variance += probability * (FIXED_SCALE as u128 - probability);
+        if got_split_bonus {
+            self.num_split_bonuses += 1;
+        }
+    }
+
+    // z_score has 9 decimal places, same as the burn rate check's
+    pub fn find_anomaly(&self, z_score: u64) -> Option<Anomaly> {
+        let z_score = z_score as u128;
+        let scale = FIXED_SCALE as u128;
+
+        let payout_limit =
+            self.expected_payout as u128 + self.payout_variance.sqrt() * z_score / scale;
+        if self.actual_payout as u128 > payout_limit {
+            return Some(Anomaly::Payouts);
+        }
+
+        let burn_deviation = self.burn_variance.sqrt() * z_score / scale;
+        if (self.actual_burn as u128) + burn_deviation < self.expected_burn as u128 {
+            return Some(Anomaly::Burns);
+        }
+
+        // Bonuses are rare enough the normal approximation needs at least 5 expected, same as the significance test
+        let split_bonus_limit = self.expected_split_bonuses as u128
+            + self.split_bonus_variance.sqrt() * z_score / scale;
+        if self.expected_split_bonuses as u128 >= 5 * scale
+            && self.num_split_bonuses as u128 * scale > split_bonus_limit
+        {
+            return Some(Anomaly::SplitBonuses);
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const Z_SCORE: u64 = 4_000_000_000; // 4 sigma
+
+    #[test]
+    fn test_fair_play_has_no_anomaly() {
+        use rand::Rng;
+
+        let mut rng = rand::thread_rng();
+        let mut stats = AnomalyStats::default();
+        for _ in 0..10_000 {
+            let num_splits = rng.gen_range(100..900u16);
+            let stake = rng.gen_range(0..=100u64) * 1_000_000_000;
+            let payout_value = rng.gen_range(1..200u64) * 1_000_000_000;
+            let opponent_split = rng.gen_range(0..1_000u16) < num_splits;
+            if opponent_split {
+                stats.record_matchup(stake, payout_value, num_splits, payout_value, 0);
+                stats.record_split_bonus_chance(num_splits, rng.gen_range(0..num_splits) == 0);
+            } else {
+                stats.record_matchup(stake, payout_value, num_splits, 0, stake);
+            }
+        }
+        assert_eq!(stats.num_games, 10_000);
+        assert_eq!(stats.find_anomaly(Z_SCORE), None);
+    }
+
+    #[test]
+    fn test_lucky_payouts() {
+        let mut stats = AnomalyStats::default();
+        // Opponent splits every time at 50/50 odds
+        for _ in 0..100 {
+            stats.record_matchup(0, 10_000_000_000, 500, 10_000_000_000, 0);
+        }
+        assert_eq!(stats.find_anomaly(Z_SCORE), Some(Anomaly::Payouts));
+    }
+
+    #[test]
+    fn test_missing_burns() {
+        let mut stats = AnomalyStats::default();
+        // Never losing a stake but also not being paid, e.g. only ever playing when payouts are tiny
+        for _ in 0..100 {
+            stats.record_matchup(10_000_000_000, 0, 500, 0, 0);
+        }
+        assert_eq!(stats.find_anomaly(Z_SCORE), Some(Anomaly::Burns));
+    }
+
+    #[test]
+    fn test_too_many_split_bonuses() {
+        let mut stats = AnomalyStats::default();
+        stats.record_split_bonus_chance(1_000, true);
+        // One early bonus is nowhere near enough to go on
+        assert_eq!(stats.find_anomaly(Z_SCORE), None);
+
+        let mut stats = AnomalyStats::default();
+        for i in 0..10_000 {
+            stats.record_split_bonus_chance(1_000, i % 100 == 0);
+        }
+        // 100 bonuses when 10 were expected
+        assert_eq!(stats.num_split_bonuses, 100);
+        assert_eq!(stats.expected_split_bonuses, 10_000_000_000);
+        assert_eq!(stats.find_anomaly(Z_SCORE), Some(Anomaly::SplitBonuses));
+    }
+}
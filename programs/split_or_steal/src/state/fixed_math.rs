@@ -0,0 +1,182 @@
+// Fixed point helpers for the luck checks, there's no float support worth using on chain
+
+// 9 decimal places, same as the rest of the stats
+pub const FIXED_SCALE: i64 = 1_000_000_000;
+// 18 decimal places, used where 9 isn't enough precision
+pub const FIXED_SCALE_18: u128 = 1_000_000_000_000_000_000;
+pub const LN_2_SCALED: i128 = 693_147_181;
+const LN_2_SCALED_18: i128 = 693_147_180_559_945_309;
+// ln(1000 / 999), the log of the chance of not getting the split bonus on one game
+const LN_1000_OVER_999_SCALED_18: i128 = 1_000_500_333_583_534;
+const ATANH_SERIES_TERMS: i128 = 12;
+const EXP_SERIES_TERMS: u128 = 24;
+// split_bonus_probability takes one step per bonus, so callers stop asking past this many
+// Even at its most likely, exactly 1000 bonuses is only about a 1.3% chance
+pub const MAX_SPLIT_BONUS_PROBABILITY_TERMS: u64 = 1_000;
+
+// ln(x) for x >= 1, both scaled by 1e9
+pub fn ln_fixed(x_scaled: u64) -> i64 {
+    assert!(
+        x_scaled >= FIXED_SCALE as u64,
+        "ln_fixed only handles x >= 1"
+    );
+    let scale = FIXED_SCALE as i128;
+
+    // Pull out powers of two so the series only ever sees 1 <= m < 2
+    let mut mantissa = x_scaled as i128;
+    let mut num_halvings = 0;
+    while mantissa >= 2 * scale {
+        mantissa /= 2;
+        num_halvings += 1;
+    }
+
+    // ln(m) = 2 * atanh((m - 1) / (m + 1)), z is at most 1/3 so it converges quickly
+    let z = (mantissa - scale) * scale / (mantissa + scale);
+    let z_squared = z * z / scale;
+    let mut term = z;
+    let mut sum = 0;
+    for n in 0..ATANH_SERIES_TERMS {
+        sum += term / (2 * n + 1);
+        term = term * z_squared / scale;
+    }
+
+    (num_halvings * LN_2_SCALED + 2 * sum) as i64
+}
+
+// ln(numerator / denominator) scaled by 1e9, works either side of one
+pub fn ln_ratio(numerator: u64, denominator: u64) -> i64 {
+    if numerator >= denominator {
+        ln_fixed((numerator as u128 * FIXED_SCALE as u128 / denominator as u128) as u64)
+    } else {
+        -ln_fixed((denominator as u128 * FIXED_SCALE as u128 / numerator as u128) as u64)
+    }
+}
+
+// e^x with x scaled by 1e18, result scaled by 1e18. Anything under e^-41 rounds to zero anyway
+pub fn exp_fixed_18(x_scaled: i128) -> u128 {
+    if x_scaled < -42 * FIXED_SCALE_18 as i128 {
+        return 0;
+    }
+    // e^x = 2^k * e^r with 0 <= r < ln 2
+    let num_doublings = x_scaled.div_euclid(LN_2_SCALED_18);
+    let remainder = x_scaled.rem_euclid(LN_2_SCALED_18) as u128;
+
+    let mut term = FIXED_SCALE_18;
+    let mut sum = FIXED_SCALE_18;
+    for n in 1..EXP_SERIES_TERMS {
+        term = term * remainder / FIXED_SCALE_18 / n;
+        sum += term;
+    }
+
+    if num_doublings >= 0 {
+        sum << num_doublings
+    } else {
+        sum >> -num_doublings
+    }
+}
+
+// Chance of getting exactly num_bonuses split bonuses in num_games games at the constant 1/1000 odds, scaled by 1e9
+pub fn split_bonus_probability(num_games: u64, num_bonuses: u64) -> u64 {
+    debug_assert!(num_bonuses <= MAX_SPLIT_BONUS_PROBABILITY_TERMS);
+    if num_bonuses > num_games {
+        return 0;
+    }
+
+    // C(n, k) / 1000^k built up one factor at a time, kept as mantissa * 2^exponent so nothing overflows
+    let mut mantissa = FIXED_SCALE_18;
+    let mut exponent: i128 = 0;
+    for i in 0..num_bonuses {
+        mantissa = mantissa * (num_games - i) as u128 / ((num_bonuses - i) as u128 * 1_000);
+        while mantissa >= 2 * FIXED_SCALE_18 {
+            mantissa >>= 1;
+            exponent += 1;
+        }
+        while mantissa < FIXED_SCALE_18 {
+            mantissa <<= 1;
+            exponent -= 1;
+        }
+    }
+
+    // Times (999 / 1000)^(n - k), folded in with the power of two so it's a single exp
+    let log_factor =
+        exponent * LN_2_SCALED_18 - (num_games - num_bonuses) as i128 * LN_1000_OVER_999_SCALED_18;
+    (mantissa * exp_fixed_18(log_factor) / FIXED_SCALE_18 / 1_000_000_000) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ln_fixed() {
+        assert_eq!(ln_fixed(1_000_000_000), 0);
+        for x in [
+            1.000_000_001f64,
+            1.5,
+            2.0,
+            std::f64::consts::E,
+            10.0,
+            99.0,
+            1e9,
+        ] {
+            let fixed = ln_fixed((x * 1e9) as u64) as f64 / 1e9;
+            assert!(
+                (fixed - x.ln()).abs() < 1e-8,
+                "x: {}, fixed: {}, float: {}",
+                x,
+                fixed,
+                x.ln()
+            );
+        }
+    }
+
+    #[test]
+    fn test_exp_fixed() {
+        assert_eq!(exp_fixed_18(0), FIXED_SCALE_18);
+        assert_eq!(exp_fixed_18(-50 * FIXED_SCALE_18 as i128), 0);
+        for x in [-40.0f64, -10.5, -1.0, -0.001, 0.5, 1.0, 20.0] {
+            let fixed = exp_fixed_18((x * 1e18) as i128) as f64 / 1e18;
+            assert!(
+                // Relative error, apart from the last 1e-18 digit on the tiny ones
+                (fixed - x.exp()).abs() <= x.exp() * 1e-12 + 2e-18,
+                "x: {}, fixed: {}, float: {}",
+                x,
+                fixed,
+                x.exp()
+            );
+        }
+    }
+
+    fn split_bonus_probability_f64(num_games: u64, num_bonuses: u64) -> f64 {
+        let n = num_games as f64;
+        let k = num_bonuses as f64;
+        let ln_choose: f64 = (0..num_bonuses)
+            .map(|i| ((num_games - i) as f64 / (num_bonuses - i) as f64).ln())
+            .sum();
+        (ln_choose + k * 0.001f64.ln() + (n - k) * 0.999f64.ln()).exp()
+    }
+
+    #[test]
+    fn test_split_bonus_probability() {
+        use rand::Rng;
+
+        assert_eq!(split_bonus_probability(5, 6), 0);
+        assert_eq!(split_bonus_probability(0, 0), 1_000_000_000);
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let num_games = rng.gen_range(1..200_000);
+            let num_bonuses = rng.gen_range(0..300.min(num_games));
+            let fixed = split_bonus_probability(num_games, num_bonuses) as f64 / 1e9;
+            let float = split_bonus_probability_f64(num_games, num_bonuses);
+            assert!(
+                (fixed - float).abs() < 1e-8,
+                "n: {}, k: {}, fixed: {}, float: {}",
+                num_games,
+                num_bonuses,
+                fixed,
+                float
+            );
+        }
+    }
+}
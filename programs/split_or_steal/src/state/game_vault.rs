@@ -24,13 +24,65 @@ pub struct GameVault {
     pub num_hundred_stakes: u32,
     pub num_splits: u16,
     pub num_steals: u16,
+    pub jackpot_bump: u8,
+    // Tokens currently sitting in the jackpot token account
+    pub jackpot_amount: u64,
+    pub jackpot_contributed: u64,
+    pub jackpot_paid_out: u64,
+    pub num_jackpots: u32,
+    pub config: VaultConfig,
+    pub num_games: u64,
+    pub season: u32,
+    pub season_start_slot: u64,
+    pub season_num_games: u64,
+    pub throttle: PlayThrottle,
+    pub paused: bool,
+    pub anomaly_stats: AnomalyStats,
+}
+
+// The vault as originally deployed, before the jackpot and everything after it. Read by migrate_vault only
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct LegacyGameVault {
+    pub creator: Pubkey,
+    pub bump: u8,
+    pub token_bump: u8,
+    pub initial_tokens: u64,
+    pub mint_remaining: u64,
+    pub burned_amount: u64,
+    pub participation_flags: [u8; BUFFER_SIZE_USIZE],
+    pub current_index: u16,
+    pub num_zero_stakes: u32,
+    pub num_hundred_stakes: u32,
+    pub num_splits: u16,
+    pub num_steals: u16,
 }
 
 impl GameVault {
     pub const SCALING_FACTOR: u128 = 1_000_000_000;
 
-    pub fn initialize(&mut self, creator: Pubkey, initial_tokens: u64, bump: u8, token_bump: u8) {
-        *self = GameVault {
+    // Keeps the game exactly where it was, everything added since starts off like a fresh vault
+    pub fn from_legacy(legacy: &LegacyGameVault, current_slot: u64) -> Self {
+        GameVault {
+            mint_remaining: legacy.mint_remaining,
+            burned_amount: legacy.burned_amount,
+            participation_flags: legacy.participation_flags,
+            current_index: legacy.current_index,
+            num_zero_stakes: legacy.num_zero_stakes,
+            num_hundred_stakes: legacy.num_hundred_stakes,
+            num_splits: legacy.num_splits,
+            num_steals: legacy.num_steals,
+            season_start_slot: current_slot,
+            ..GameVault::new(
+                legacy.creator,
+                legacy.initial_tokens,
+                legacy.bump,
+                legacy.token_bump,
+            )
+        }
+    }
+
+    pub fn new(creator: Pubkey, initial_tokens: u64, bump: u8, token_bump: u8) -> Self {
+        GameVault {
             creator,
             bump,
             token_bump,
@@ -43,7 +95,24 @@ impl GameVault {
             num_zero_stakes: 0,
             num_hundred_stakes: 0,
             num_steals: 0,
-        };
+            jackpot_bump: 0,
+            jackpot_amount: 0,
+            jackpot_contributed: 0,
+            jackpot_paid_out: 0,
+            num_jackpots: 0,
+            config: VaultConfig::default(),
+            num_games: 0,
+            season: 0,
+            season_start_slot: 0,
+            season_num_games: 0,
+            throttle: PlayThrottle::default(),
+            paused: false,
+            anomaly_stats: AnomalyStats::default(),
+        }
+    }
+
+    pub fn initialize(&mut self, creator: Pubkey, initial_tokens: u64, bump: u8, token_bump: u8) {
+        *self = GameVault::new(creator, initial_tokens, bump, token_bump);
     }
 
     pub fn add_entry(&mut self, entry: Choice) {
@@ -62,6 +131,18 @@ impl GameVault {
         self.current_index = (self.current_index + 1) % BUFFER_SIZE_U16;
     }
 
+    pub fn record_game(&mut self) {
+        self.num_games += 1;
+        self.season_num_games += 1;
+    }
+
+    // The ring buffer carries over, resetting it would tell everyone what the next opponents choose
+    pub fn start_new_season(&mut self, current_slot: u64) {
+        self.season += 1;
+        self.season_start_slot = current_slot;
+        self.season_num_games = 0;
+    }
+
     // Just here for tests really
     pub fn get_current_entry(&self) -> Choice {
         match self.participation_flags[self.current_index as usize] {
@@ -125,6 +206,18 @@ impl GameVault {
         let split_bonus_u128 = expected_steal_payoff - expected_split_payoff;
         split_bonus_u128.try_into().unwrap()
     }
+
+    pub fn set_paused(&mut self, paused: bool, reset_anomaly_stats: bool) {
+        self.paused = paused;
+        if reset_anomaly_stats {
+            self.anomaly_stats = AnomalyStats::default();
+        }
+    }
+
+    // The part of a lost stake that goes to the jackpot, the rest is burned
+    pub fn calculate_jackpot_contribution(&self, stake_amount: u64) -> u64 {
+        apply_basis_points(stake_amount, self.config.jackpot_bps)
+    }
 }
 
 #[cfg(test)]
@@ -133,20 +226,7 @@ mod tests {
 
     #[test]
     fn test_ring_buffer() {
-        let mut vault = GameVault {
-            creator: Pubkey::default(),
-            bump: 0,
-            token_bump: 0,
-            mint_remaining: 1_000_000_000,
-            participation_flags: [Choice::Split as u8; 1000],
-            current_index: 0,
-            num_splits: 1_000,
-            num_steals: 0,
-            initial_tokens: 0,
-            num_zero_stakes: 0,
-            num_hundred_stakes: 0,
-            burned_amount: 0,
-        };
+        let mut vault = GameVault::new(Pubkey::default(), 1_000_000_000, 0, 0);
 
         vault.add_entry(Choice::Steal); // Add steal
         assert_eq!(vault.get_current_entry(), Choice::Split); // Current entry is still split because index has moved
@@ -209,18 +289,9 @@ mod tests {
         for num_splits in 1..=1000 {
             for stake_amount_base in 1..=100 {
                 let game_vault = GameVault {
-                    creator: Pubkey::default(),
-                    bump: 0,
-                    token_bump: 0,
-                    mint_remaining: 0,
-                    participation_flags: [0; 1000],
-                    current_index: 0,
                     num_splits,
                     num_steals: BUFFER_SIZE_U16 - num_splits,
-                    initial_tokens: 0,
-                    num_zero_stakes: 0,
-                    num_hundred_stakes: 0,
-                    burned_amount: 0,
+                    ..GameVault::new(Pubkey::default(), 0, 0, 0)
                 };
 
                 let stake_amount: u64 = stake_amount_base * u64::pow(10, DEFAULT_DECIMALS.into()); // 1 to 100 tokens with 9 decimal places
@@ -259,18 +330,9 @@ mod tests {
         for num_splits in 1..=1000 {
             for stake_amount_base in 1..=100 {
                 let game_vault = GameVault {
-                    creator: Pubkey::default(),
-                    bump: 0,
-                    token_bump: 0,
-                    mint_remaining: 0,
-                    participation_flags: [0; 1000],
-                    current_index: 0,
                     num_splits,
                     num_steals: BUFFER_SIZE_U16 - num_splits,
-                    num_zero_stakes: 0,
-                    num_hundred_stakes: 0,
-                    initial_tokens: 0,
-                    burned_amount: 0,
+                    ..GameVault::new(Pubkey::default(), 0, 0, 0)
                 };
 
                 let stake_amount: u64 = stake_amount_base * 1_000_000_000; // 1 to 100 tokens with 9 decimal places
@@ -331,18 +393,9 @@ mod tests {
         for num_splits in 1..=1000 {
             for stake_amount_base in 1..=100 {
                 let game_vault = GameVault {
-                    creator: Pubkey::default(),
-                    bump: 0,
-                    token_bump: 0,
-                    mint_remaining: 0,
-                    participation_flags: [0; 1000],
-                    current_index: 0,
                     num_splits,
-                    num_steals: 1000 - num_splits,
-                    num_zero_stakes: 0,
-                    num_hundred_stakes: 0,
-                    initial_tokens: 0,
-                    burned_amount: 0,
+                    num_steals: BUFFER_SIZE_U16 - num_splits,
+                    ..GameVault::new(Pubkey::default(), 0, 0, 0)
                 };
 
                 let stake_amount: u64 = stake_amount_base * 1_000_000_000; // 1 to 100 tokens with 9 decimal places
@@ -381,4 +434,89 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_jackpot_contribution() {
+        let mut vault = GameVault::new(Pubkey::default(), 1_000_000_000, 0, 0);
+
+        // Mode is off by default so everything gets burned
+        assert_eq!(vault.calculate_jackpot_contribution(100_000_000_000), 0);
+
+        vault.config.jackpot_bps = 2_500; // 25%
+        assert_eq!(
+            vault.calculate_jackpot_contribution(100_000_000_000),
+            25_000_000_000
+        );
+        assert_eq!(vault.calculate_jackpot_contribution(0), 0);
+
+        vault.config.jackpot_bps = MAX_BASIS_POINTS;
+        assert_eq!(
+            vault.calculate_jackpot_contribution(100_000_000_000),
+            100_000_000_000
+        );
+    }
+
+    #[test]
+    fn test_start_new_season() {
+        let mut vault = GameVault::new(Pubkey::default(), 1_000_000_000, 0, 0);
+
+        for _ in 0..10 {
+            vault.add_entry(Choice::Steal);
+            vault.record_game();
+        }
+
+        // Only the season counters reset
+        vault.start_new_season(100);
+        assert_eq!(vault.season, 1);
+        assert_eq!(vault.season_start_slot, 100);
+        assert_eq!(vault.season_num_games, 0);
+        assert_eq!(vault.num_games, 10);
+        assert_eq!(vault.num_steals, 10);
+        assert_eq!(vault.current_index, 10);
+
+        vault.record_game();
+        vault.start_new_season(200);
+        assert_eq!(vault.season, 2);
+        assert_eq!(vault.num_games, 11);
+        assert_eq!(vault.num_splits, 990);
+        assert_eq!(vault.num_steals, 10);
+        assert_eq!(vault.get_entry_at(0), Choice::Steal);
+    }
+
+    #[test]
+    fn test_from_legacy() {
+        let mut participation_flags = [Choice::Split as u8; 1000];
+        participation_flags[3] = Choice::Steal as u8;
+        let legacy = LegacyGameVault {
+            creator: Pubkey::new_unique(),
+            bump: 254,
+            token_bump: 253,
+            initial_tokens: 1_000_000_000,
+            mint_remaining: 900_000_000,
+            burned_amount: 5_000_000,
+            participation_flags,
+            current_index: 4,
+            num_zero_stakes: 7,
+            num_hundred_stakes: 2,
+            num_splits: 999,
+            num_steals: 1,
+        };
+        let data = legacy.try_to_vec().unwrap();
+        // The size of the vault as originally deployed
+        assert_eq!(data.len(), 1072);
+
+        let decoded = LegacyGameVault::deserialize(&mut &data[..]).unwrap();
+        let vault = GameVault::from_legacy(&decoded, 42);
+        assert_eq!(vault.creator, legacy.creator);
+        assert_eq!(vault.bump, 254);
+        assert_eq!(vault.token_bump, 253);
+        assert_eq!(vault.mint_remaining, 900_000_000);
+        assert_eq!(vault.burned_amount, 5_000_000);
+        assert_eq!(vault.get_entry_at(3), Choice::Steal);
+        assert_eq!(vault.current_index, 4);
+        assert_eq!((vault.num_splits, vault.num_steals), (999, 1));
+        assert_eq!(vault.jackpot_bump, 0);
+        assert_eq!(vault.season_start_slot, 42);
+        assert_eq!(vault.config, VaultConfig::default());
+    }
 }
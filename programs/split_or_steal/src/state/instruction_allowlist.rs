@@ -0,0 +1,231 @@
+use super::*;
+
+pub const MAX_ALLOWED_PROGRAMS: usize = 16;
+// Memo v2 and v1, they can't read the game's outcome or fail because of it
+// Anything else after play_game could check the player's balance and revert a loss
+pub const PASSIVE_PROGRAM_IDS: [Pubkey; 2] = [
+    pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"),
+    pubkey!("Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo"),
+];
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum InstructionPosition {
+    Before,
+    After,
+    Either,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct AllowedProgram {
+    pub program_id: Pubkey,
+    pub position: InstructionPosition,
+}
+
+const EMPTY_ALLOWED_PROGRAM: AllowedProgram = AllowedProgram {
+    program_id: Pubkey::new_from_array([0; 32]),
+    position: InstructionPosition::Either,
+};
+
+// Programs allowed as top level instructions next to play_game, compute budget is always allowed before
+#[account]
+pub struct InstructionAllowlist {
+    pub bump: u8,
+    pub num_programs: u8,
+    pub programs: [AllowedProgram; MAX_ALLOWED_PROGRAMS],
+}
+
+impl InstructionAllowlist {
+    pub fn set_programs(
+        &mut self,
+        programs: &[AllowedProgram],
+        game_program_id: &Pubkey,
+    ) -> Result<()> {
+        require_gte!(
+            MAX_ALLOWED_PROGRAMS,
+            programs.len(),
+            SplitOrStealError::TooManyAllowedPrograms
+        );
+        for program in programs {
+            // Allowing ourselves would let several games share a transaction
+            require_keys_neq!(
+                program.program_id,
+                *game_program_id,
+                SplitOrStealError::CannotAllowGameProgram
+            );
+            require!(
+                program.position == InstructionPosition::Before
+                    || PASSIVE_PROGRAM_IDS.contains(&program.program_id),
+                SplitOrStealError::ProgramNotPassive
+            );
+        }
+
+        self.programs = [EMPTY_ALLOWED_PROGRAM; MAX_ALLOWED_PROGRAMS];
+        self.programs[..programs.len()].copy_from_slice(programs);
+        self.num_programs = programs.len() as u8;
+        Ok(())
+    }
+
+    // Position is where the other instruction sits relative to play_game, either Before or After
+    pub fn check_companion(
+        &self,
+        companion_program_id: &Pubkey,
+        position: InstructionPosition,
+        game_program_id: &Pubkey,
+    ) -> Result<()> {
+        require_keys_neq!(
+            *companion_program_id,
+            *game_program_id,
+            SplitOrStealError::MultipleGameInstructions
+        );
+
+        let is_allowed = self.programs[..self.num_programs as usize]
+            .iter()
+            .any(|program| {
+                program.program_id == *companion_program_id
+                    && (program.position == InstructionPosition::Either
+                        || program.position == position)
+            });
+        match position {
+            InstructionPosition::After => require!(
+                is_allowed && PASSIVE_PROGRAM_IDS.contains(companion_program_id),
+                SplitOrStealError::InstructionNotAllowedAfter
+            ),
+            _ => require!(is_allowed, SplitOrStealError::InstructionNotAllowedBefore),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_allowlist() -> InstructionAllowlist {
+        InstructionAllowlist {
+            bump: 0,
+            num_programs: 0,
+            programs: [EMPTY_ALLOWED_PROGRAM; MAX_ALLOWED_PROGRAMS],
+        }
+    }
+
+    #[test]
+    fn test_check_companion_positions() {
+        let game_program_id = Pubkey::new_unique();
+        let memo = PASSIVE_PROGRAM_IDS[0];
+        let memo_v1 = PASSIVE_PROGRAM_IDS[1];
+        let nonce = Pubkey::new_unique();
+        let unknown = Pubkey::new_unique();
+
+        let mut allowlist = new_allowlist();
+        allowlist
+            .set_programs(
+                &[
+                    AllowedProgram {
+                        program_id: memo,
+                        position: InstructionPosition::Either,
+                    },
+                    AllowedProgram {
+                        program_id: memo_v1,
+                        position: InstructionPosition::After,
+                    },
+                    AllowedProgram {
+                        program_id: nonce,
+                        position: InstructionPosition::Before,
+                    },
+                ],
+                &game_program_id,
+            )
+            .unwrap();
+
+        let before = InstructionPosition::Before;
+        let after = InstructionPosition::After;
+        assert!(allowlist
+            .check_companion(&memo, before, &game_program_id)
+            .is_ok());
+        assert!(allowlist
+            .check_companion(&memo, after, &game_program_id)
+            .is_ok());
+        assert!(allowlist
+            .check_companion(&memo_v1, after, &game_program_id)
+            .is_ok());
+        assert!(allowlist
+            .check_companion(&nonce, before, &game_program_id)
+            .is_ok());
+
+        assert_eq!(
+            allowlist.check_companion(&memo_v1, before, &game_program_id),
+            Err(SplitOrStealError::InstructionNotAllowedBefore.into())
+        );
+        assert_eq!(
+            allowlist.check_companion(&nonce, after, &game_program_id),
+            Err(SplitOrStealError::InstructionNotAllowedAfter.into())
+        );
+        assert_eq!(
+            allowlist.check_companion(&unknown, after, &game_program_id),
+            Err(SplitOrStealError::InstructionNotAllowedAfter.into())
+        );
+        assert_eq!(
+            allowlist.check_companion(&game_program_id, before, &game_program_id),
+            Err(SplitOrStealError::MultipleGameInstructions.into())
+        );
+    }
+
+    #[test]
+    fn test_set_programs_validation() {
+        let game_program_id = Pubkey::new_unique();
+        let mut allowlist = new_allowlist();
+
+        let too_many = [AllowedProgram {
+            program_id: Pubkey::new_unique(),
+            position: InstructionPosition::Before,
+        }; MAX_ALLOWED_PROGRAMS + 1];
+        assert!(allowlist.set_programs(&too_many, &game_program_id).is_err());
+
+        let game_program = [AllowedProgram {
+            program_id: game_program_id,
+            position: InstructionPosition::Either,
+        }];
+        assert!(allowlist
+            .set_programs(&game_program, &game_program_id)
+            .is_err());
+
+        // Only passive programs can go after the game, a guard there could revert losses
+        let guard = Pubkey::new_unique();
+        for position in [InstructionPosition::After, InstructionPosition::Either] {
+            assert_eq!(
+                allowlist.set_programs(
+                    &[AllowedProgram {
+                        program_id: guard,
+                        position,
+                    }],
+                    &game_program_id
+                ),
+                Err(SplitOrStealError::ProgramNotPassive.into())
+            );
+        }
+
+        // Setting replaces the old list entirely
+        let memo = PASSIVE_PROGRAM_IDS[0];
+        allowlist
+            .set_programs(&too_many[..2], &game_program_id)
+            .unwrap();
+        allowlist
+            .set_programs(
+                &[AllowedProgram {
+                    program_id: memo,
+                    position: InstructionPosition::After,
+                }],
+                &game_program_id,
+            )
+            .unwrap();
+        assert_eq!(allowlist.num_programs, 1);
+        assert_eq!(allowlist.programs[1], EMPTY_ALLOWED_PROGRAM);
+        assert!(allowlist
+            .check_companion(
+                &too_many[0].program_id,
+                InstructionPosition::Before,
+                &game_program_id
+            )
+            .is_err());
+    }
+}
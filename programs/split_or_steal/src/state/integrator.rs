@@ -0,0 +1,98 @@
+use super::*;
+
+// Integrator programs sign for this PDA of their own to prove the CPI really comes from them
+pub const INTEGRATOR_AUTHORITY_SEED: &[u8] = b"split or steal integrator";
+
+// A program allowed to call play_game_cpi, set up by the creator
+#[account]
+pub struct Integrator {
+    pub bump: u8,
+    pub program_id: Pubkey,
+    // PDA of INTEGRATOR_AUTHORITY_SEED under program_id
+    pub authority: Pubkey,
+    pub revenue_token_account: Pubkey,
+    // The integrator's share of each payout, taken out of the player's winnings, in basis points
+    pub revenue_share_bps: u16,
+    pub enabled: bool,
+    pub num_games: u64,
+    pub tokens_earned: u64,
+}
+
+impl Integrator {
+    pub fn configure(
+        &mut self,
+        bump: u8,
+        program_id: Pubkey,
+        revenue_token_account: Pubkey,
+        revenue_share_bps: u16,
+        enabled: bool,
+    ) -> Result<()> {
+        require_gte!(
+            MAX_BASIS_POINTS,
+            revenue_share_bps,
+            SplitOrStealError::InvalidBasisPoints
+        );
+        self.bump = bump;
+        self.program_id = program_id;
+        self.authority = Pubkey::find_program_address(&[INTEGRATOR_AUTHORITY_SEED], &program_id).0;
+        self.revenue_token_account = revenue_token_account;
+        self.revenue_share_bps = revenue_share_bps;
+        self.enabled = enabled;
+        Ok(())
+    }
+
+    pub fn record_game(&mut self, revenue_share: u64) {
+        self.num_games += 1;
+        self.tokens_earned += revenue_share;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_integrator() -> Integrator {
+        Integrator {
+            bump: 0,
+            program_id: Pubkey::default(),
+            authority: Pubkey::default(),
+            revenue_token_account: Pubkey::default(),
+            revenue_share_bps: 0,
+            enabled: false,
+            num_games: 0,
+            tokens_earned: 0,
+        }
+    }
+
+    #[test]
+    fn test_configure_integrator() {
+        let program_id = Pubkey::new_unique();
+        let revenue_token_account = Pubkey::new_unique();
+        let mut integrator = new_integrator();
+
+        integrator
+            .configure(255, program_id, revenue_token_account, 500, true)
+            .unwrap();
+        assert_eq!(
+            integrator.authority,
+            Pubkey::find_program_address(&[INTEGRATOR_AUTHORITY_SEED], &program_id).0
+        );
+        assert_eq!(integrator.revenue_share_bps, 500);
+        assert!(integrator.enabled);
+
+        assert!(integrator
+            .configure(
+                255,
+                program_id,
+                revenue_token_account,
+                MAX_BASIS_POINTS + 1,
+                true
+            )
+            .is_err());
+
+        integrator.record_game(1_000);
+        integrator.record_game(0);
+        assert_eq!(integrator.num_games, 2);
+        assert_eq!(integrator.tokens_earned, 1_000);
+    }
+}
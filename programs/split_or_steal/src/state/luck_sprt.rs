@@ -0,0 +1,181 @@
+use super::*;
+
+// Wald's SPRT on whether the player's opponent splits more often than the ring buffer says it should.
+// H0 is the win chance the vault advertised for each game, H1 is those odds multiplied by the configured odds ratio.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct LuckSprt {
+    pub log_likelihood_ratio: i64,
+    pub num_games: u32,
+    // Times the test finished and started over, either accepting the player as fair or after flagging them.
+    // Every restart halves the alpha the next test gets, see boundaries
+    pub num_restarts: u32,
+}
+
+impl LuckSprt {
+    // Upper and lower Wald boundaries, ln((1 - beta) / alpha) and ln(beta / (1 - alpha)).
+    // The k-th test only gets alpha / 2^(k + 1), otherwise restarting forever would flag every fair player eventually.
+    // Spread like this the chance of ever flagging a fair player stays under alpha however long they play
+    pub fn boundaries(&self, config: &VaultConfig) -> (i64, i64) {
+        let alpha = config.sprt_alpha_bps as u64;
+        let beta = config.sprt_beta_bps as u64;
+        let max = MAX_BASIS_POINTS as u64;
+        let alpha_spent = (self.num_restarts as i64 + 1).saturating_mul(LN_2_SCALED as i64);
+        (
+            ln_ratio(max - beta, alpha).saturating_add(alpha_spent),
+            ln_ratio(beta, max - alpha),
+        )
+    }
+
+    pub fn update(&mut self, opponent_split: bool, num_splits: u16, config: &VaultConfig) {
+        let odds_ratio_bps = config.sprt_odds_ratio_bps as u64;
+        let max = MAX_BASIS_POINTS as u64;
+
+        // With odds multiplied by theta the H1 win chance is theta * p / (1 - p + theta * p),
+        // so both likelihood ratios share the 1 + (theta - 1) * p denominator
+        let denominator_scaled =
+            FIXED_SCALE as u64 + (odds_ratio_bps - max) * num_splits as u64 * 100;
+        let ln_denominator = ln_fixed(denominator_scaled);
+        if opponent_split {
+            self.log_likelihood_ratio += ln_ratio(odds_ratio_bps, max) - ln_denominator;
+        } else {
+            self.log_likelihood_ratio -= ln_denominator;
+        }
+        self.num_games += 1;
+
+        let (_, lower) = self.boundaries(config);
+        if self.log_likelihood_ratio <= lower {
+            self.restart();
+        }
+    }
+
+    pub fn restart(&mut self) {
+        self.log_likelihood_ratio = 0;
+        self.num_restarts += 1;
+    }
+
+    pub fn passes(&self, config: &VaultConfig) -> bool {
+        let (upper, _) = self.boundaries(config);
+        self.log_likelihood_ratio < upper
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    fn sprt_config() -> VaultConfig {
+        VaultConfig {
+            sprt_odds_ratio_bps: 20_000, // Twice the odds
+            sprt_alpha_bps: 100,         // 1%
+            sprt_beta_bps: 100,          // 1%
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_boundaries() {
+        let mut sprt = LuckSprt::default();
+        let (upper, lower) = sprt.boundaries(&sprt_config());
+        let expected = (0.99f64 / 0.01).ln();
+        // The first test gets half of alpha
+        assert!((upper as f64 / 1e9 - (0.99f64 / 0.005).ln()).abs() < 1e-8);
+        assert!((lower as f64 / 1e9 + expected).abs() < 1e-8);
+
+        // And every restart half of what's left
+        sprt.num_restarts = 3;
+        let (upper, restarted_lower) = sprt.boundaries(&sprt_config());
+        assert!((upper as f64 / 1e9 - (0.99f64 / 0.000625).ln()).abs() < 1e-8);
+        assert_eq!(restarted_lower, lower);
+    }
+
+    // Same update in floats, without the restart at the lower boundary
+    fn log_likelihood_ratio_f64(games: &[(bool, u16)], odds_ratio: f64) -> f64 {
+        games
+            .iter()
+            .map(|&(opponent_split, num_splits)| {
+                let p = num_splits as f64 / 1000.0;
+                let p1 = odds_ratio * p / (1.0 - p + odds_ratio * p);
+                if opponent_split {
+                    (p1 / p).ln()
+                } else {
+                    ((1.0 - p1) / (1.0 - p)).ln()
+                }
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_randomized_against_float() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let config = VaultConfig {
+                sprt_odds_ratio_bps: rng.gen_range(10_001..30_000),
+                // Boundaries far enough apart that the test never restarts
+                sprt_alpha_bps: 1,
+                sprt_beta_bps: 1,
+                ..Default::default()
+            };
+            let mut sprt = LuckSprt::default();
+            let mut games = Vec::new();
+            for _ in 0..rng.gen_range(1..50) {
+                let num_splits = rng.gen_range(1..1_000u16);
+                let opponent_split = rng.gen_range(0..1_000u16) < num_splits;
+                sprt.update(opponent_split, num_splits, &config);
+                games.push((opponent_split, num_splits));
+            }
+            if sprt.num_restarts > 0 {
+                continue;
+            }
+
+            let expected =
+                log_likelihood_ratio_f64(&games, config.sprt_odds_ratio_bps as f64 / 10_000.0);
+            let actual = sprt.log_likelihood_ratio as f64 / 1e9;
+            assert!(
+                (actual - expected).abs() < 1e-6,
+                "fixed: {}, float: {}",
+                actual,
+                expected
+            );
+            assert_eq!(sprt.num_games as usize, games.len());
+        }
+    }
+
+    #[test]
+    fn test_false_positive_rate() {
+        let config = sprt_config();
+        let mut rng = rand::thread_rng();
+        let num_players = 200;
+
+        let mut num_flagged = 0;
+        for _ in 0..num_players {
+            let mut sprt = LuckSprt::default();
+            // Long enough for hundreds of restarts, alpha has to hold over the player's whole lifetime
+            for _ in 0..10_000 {
+                // Fair games, the opponent splits exactly as often as the vault says
+                let num_splits = rng.gen_range(100..900u16);
+                let opponent_split = rng.gen_range(0..1_000u16) < num_splits;
+                sprt.update(opponent_split, num_splits, &config);
+                if !sprt.passes(&config) {
+                    num_flagged += 1;
+                    break;
+                }
+            }
+        }
+        // Alpha is 1%, leave room for randomness
+        assert!(num_flagged < 8, "flagged {} fair players", num_flagged);
+    }
+
+    #[test]
+    fn test_flags_lucky_player() {
+        let config = sprt_config();
+        let mut sprt = LuckSprt::default();
+
+        // Opponent splits every time at 50/50 odds, should be caught well inside 20 games
+        for _ in 0..20 {
+            sprt.update(true, 500, &config);
+        }
+        assert!(!sprt.passes(&config));
+    }
+}
@@ -1,7 +1,37 @@
 pub use super::*;
 
+pub mod achievement;
+pub mod anomaly_stats;
+pub mod fixed_math;
 pub mod game_vault;
+pub mod instruction_allowlist;
+pub mod integrator;
+pub mod luck_sprt;
+pub mod play_session;
+pub mod play_throttle;
+pub mod player_history;
 pub mod player_profile;
+pub mod profile_metadata;
+pub mod profile_tombstone;
+pub mod season_archive;
+pub mod team;
+pub mod treasury;
+pub mod vault_config;
 
+pub use achievement::*;
+pub use anomaly_stats::*;
+pub use fixed_math::*;
 pub use game_vault::*;
+pub use instruction_allowlist::*;
+pub use integrator::*;
+pub use luck_sprt::*;
+pub use play_session::*;
+pub use play_throttle::*;
+pub use player_history::*;
 pub use player_profile::*;
+pub use profile_metadata::*;
+pub use profile_tombstone::*;
+pub use season_archive::*;
+pub use team::*;
+pub use treasury::*;
+pub use vault_config::*;
@@ -0,0 +1,122 @@
+use super::*;
+
+// Lets an ephemeral key play for the player, the session PDA is the delegate on the player's token account
+#[account]
+pub struct PlaySession {
+    pub player: Pubkey,
+    pub session_key: Pubkey,
+    pub bump: u8,
+    // Last slot the session can be used in
+    pub expiry_slot: u64,
+    pub max_total_stake: u64,
+    pub max_games: u32,
+    pub total_staked: u64,
+    pub num_games: u32,
+}
+
+impl PlaySession {
+    pub fn initialize(
+        &mut self,
+        player: Pubkey,
+        session_key: Pubkey,
+        bump: u8,
+        expiry_slot: u64,
+        max_total_stake: u64,
+        max_games: u32,
+    ) {
+        *self = PlaySession {
+            player,
+            session_key,
+            bump,
+            expiry_slot,
+            max_total_stake,
+            max_games,
+            total_staked: 0,
+            num_games: 0,
+        };
+    }
+
+    pub fn is_active(&self, current_slot: u64) -> bool {
+        current_slot <= self.expiry_slot
+    }
+
+    pub fn record_plays(&mut self, num_games: u32, total_stake: u64) -> Result<()> {
+        require_gte!(
+            self.max_games,
+            self.num_games + num_games,
+            SplitOrStealError::SessionGameLimitReached
+        );
+        require_gte!(
+            self.max_total_stake,
+            self.total_staked + total_stake,
+            SplitOrStealError::SessionStakeLimitReached
+        );
+        self.num_games += num_games;
+        self.total_staked += total_stake;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_session() -> PlaySession {
+        let mut session = PlaySession {
+            player: Pubkey::default(),
+            session_key: Pubkey::default(),
+            bump: 0,
+            expiry_slot: 0,
+            max_total_stake: 0,
+            max_games: 0,
+            total_staked: 0,
+            num_games: 0,
+        };
+        session.initialize(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            255,
+            1_000,
+            10_000_000_000, // 10 tokens
+            3,
+        );
+        session
+    }
+
+    #[test]
+    fn test_session_expiry() {
+        let session = new_session();
+        assert!(session.is_active(0));
+        assert!(session.is_active(1_000));
+        assert!(!session.is_active(1_001));
+    }
+
+    #[test]
+    fn test_session_limits() {
+        let mut session = new_session();
+        session.record_plays(1, 4_000_000_000).unwrap();
+        session.record_plays(1, 6_000_000_000).unwrap();
+
+        // Stake limit hit, nothing gets recorded
+        assert_eq!(
+            session.record_plays(1, 1),
+            Err(SplitOrStealError::SessionStakeLimitReached.into())
+        );
+        assert_eq!(session.total_staked, 10_000_000_000);
+        assert_eq!(session.num_games, 2);
+
+        session.record_plays(1, 0).unwrap();
+        assert_eq!(
+            session.record_plays(1, 0),
+            Err(SplitOrStealError::SessionGameLimitReached.into())
+        );
+    }
+
+    #[test]
+    fn test_session_batch_counts_every_game() {
+        let mut session = new_session();
+        assert!(session.record_plays(4, 0).is_err());
+        session.record_plays(3, 0).unwrap();
+        assert_eq!(session.num_games, 3);
+    }
+}
@@ -0,0 +1,155 @@
+use super::*;
+
+// Vault wide play counters, the per player cooldown does nothing against many profiles in one bundle
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct PlayThrottle {
+    pub current_slot: u64,
+    pub plays_in_slot: u32,
+    // Count of plays that dropped mint_remaining by more than the configured amount this slot
+    pub large_drops_in_slot: u32,
+    pub window_start_slot: u64,
+    pub plays_in_window: u32,
+}
+
+impl PlayThrottle {
+    fn roll_forward(&mut self, current_slot: u64, window_slots: u64) {
+        if current_slot != self.current_slot {
+            self.current_slot = current_slot;
+            self.plays_in_slot = 0;
+            self.large_drops_in_slot = 0;
+        }
+        // Fixed windows, a window of zero slots never rolls but is never checked either
+        if window_slots > 0 && current_slot >= self.window_start_slot + window_slots {
+            self.window_start_slot = current_slot - current_slot % window_slots;
+            self.plays_in_window = 0;
+        }
+    }
+
+    pub fn record_play(&mut self, current_slot: u64, config: &VaultConfig) -> Result<()> {
+        self.roll_forward(current_slot, config.throttle_window_slots);
+        self.plays_in_slot += 1;
+        self.plays_in_window += 1;
+
+        if config.max_plays_per_slot > 0 {
+            require_gte!(
+                config.max_plays_per_slot,
+                self.plays_in_slot,
+                SplitOrStealError::SlotPlayLimitReached
+            );
+        }
+        if config.max_plays_per_window > 0 && config.throttle_window_slots > 0 {
+            require_gte!(
+                config.max_plays_per_window,
+                self.plays_in_window,
+                SplitOrStealError::WindowPlayLimitReached
+            );
+        }
+        Ok(())
+    }
+
+    pub fn record_mint_drop(
+        &mut self,
+        current_slot: u64,
+        drop_amount: u64,
+        config: &VaultConfig,
+    ) -> Result<()> {
+        if config.large_drop_amount == 0 || drop_amount <= config.large_drop_amount {
+            return Ok(());
+        }
+        self.roll_forward(current_slot, config.throttle_window_slots);
+        self.large_drops_in_slot += 1;
+        require_gte!(
+            config.max_large_drops_per_slot,
+            self.large_drops_in_slot,
+            SplitOrStealError::LargeDropLimitReached
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slot_play_limit() {
+        let config = VaultConfig {
+            max_plays_per_slot: 2,
+            ..Default::default()
+        };
+        let mut throttle = PlayThrottle::default();
+
+        assert!(throttle.record_play(10, &config).is_ok());
+        assert!(throttle.record_play(10, &config).is_ok());
+        assert!(throttle.record_play(10, &config).is_err());
+
+        // A new slot starts counting again
+        assert!(throttle.record_play(11, &config).is_ok());
+        assert_eq!(throttle.plays_in_slot, 1);
+        assert_eq!(throttle.current_slot, 11);
+
+        // Off by default
+        let mut throttle = PlayThrottle::default();
+        for _ in 0..100 {
+            assert!(throttle.record_play(10, &VaultConfig::default()).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_window_play_limit() {
+        let config = VaultConfig {
+            max_plays_per_window: 3,
+            throttle_window_slots: 10,
+            ..Default::default()
+        };
+        let mut throttle = PlayThrottle::default();
+
+        assert!(throttle.record_play(20, &config).is_ok());
+        assert_eq!(throttle.window_start_slot, 20);
+        assert!(throttle.record_play(22, &config).is_ok());
+        assert!(throttle.record_play(29, &config).is_ok());
+        assert!(throttle.record_play(29, &config).is_err());
+
+        // Next window is aligned to the window size
+        let mut throttle = PlayThrottle::default();
+        throttle.record_play(20, &config).unwrap();
+        assert!(throttle.record_play(35, &config).is_ok());
+        assert_eq!(throttle.window_start_slot, 30);
+        assert_eq!(throttle.plays_in_window, 1);
+    }
+
+    #[test]
+    fn test_large_drop_limit() {
+        let config = VaultConfig {
+            large_drop_amount: 50_000_000_000,
+            max_large_drops_per_slot: 1,
+            ..Default::default()
+        };
+        let mut throttle = PlayThrottle::default();
+
+        // Small drops never count
+        for _ in 0..10 {
+            assert!(throttle
+                .record_mint_drop(5, 50_000_000_000, &config)
+                .is_ok());
+        }
+        assert!(throttle
+            .record_mint_drop(5, 50_000_000_001, &config)
+            .is_ok());
+        assert!(throttle
+            .record_mint_drop(5, 60_000_000_000, &config)
+            .is_err());
+        assert!(throttle
+            .record_mint_drop(6, 60_000_000_000, &config)
+            .is_ok());
+
+        // Zero allowed means any large drop is rejected
+        let config = VaultConfig {
+            large_drop_amount: 1,
+            ..Default::default()
+        };
+        assert!(PlayThrottle::default()
+            .record_mint_drop(5, 2, &config)
+            .is_err());
+    }
+}
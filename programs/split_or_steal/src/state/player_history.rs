@@ -0,0 +1,147 @@
+use super::*;
+
+// Keeps the rent for a full history under about 0.04 SOL
+pub const MAX_PLAYER_HISTORY_LEN: u16 = 256;
+// Borsh size of a HistoryEntry, the choices are one byte each
+const HISTORY_ENTRY_SIZE: usize = 8 + 2 + 1 + 1 + 8 + 1;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct HistoryEntry {
+    pub slot: u64,
+    pub stake_amount: u16,
+    pub player_choice: Choice,
+    pub opponent_choice: Choice,
+    // Tokens transferred to the player, zero if the stake was burned
+    pub outcome_amount: u64,
+    pub got_split_bonus: bool,
+}
+
+// The player's last capacity games so frontends can show them without an indexer.
+// Entries fill up in order, once full next_index points at the oldest entry and gets overwritten next
+#[account]
+pub struct PlayerHistory {
+    pub bump: u8,
+    pub player: Pubkey,
+    pub capacity: u16,
+    pub next_index: u16,
+    pub entries: Vec<HistoryEntry>,
+}
+
+impl PlayerHistory {
+    pub fn space(capacity: u16) -> usize {
+        8 + 1 + 32 + 2 + 2 + 4 + capacity as usize * HISTORY_ENTRY_SIZE
+    }
+
+    pub fn record(&mut self, entry: HistoryEntry) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.len() < self.capacity as usize {
+            self.entries.push(entry);
+        } else {
+            self.entries[self.next_index as usize] = entry;
+        }
+        self.next_index = (self.next_index + 1) % self.capacity;
+    }
+
+    // Oldest first
+    pub fn recent(&self) -> Vec<HistoryEntry> {
+        let (newer, older) = self.entries.split_at(self.next_index as usize);
+        if self.entries.len() < self.capacity as usize {
+            return self.entries.clone();
+        }
+        older.iter().chain(newer).copied().collect()
+    }
+
+    // Keeps the most recent games that fit, the account has to be reallocated to space(capacity) around this
+    pub fn resize(&mut self, capacity: u16) -> Result<()> {
+        require_gte!(
+            MAX_PLAYER_HISTORY_LEN,
+            capacity,
+            SplitOrStealError::HistoryTooLong
+        );
+        let recent = self.recent();
+        let num_kept = recent.len().min(capacity as usize);
+        self.entries = recent[recent.len() - num_kept..].to_vec();
+        self.capacity = capacity;
+        self.next_index = if capacity == 0 {
+            0
+        } else {
+            (num_kept % capacity as usize) as u16
+        };
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(slot: u64) -> HistoryEntry {
+        HistoryEntry {
+            slot,
+            stake_amount: 1,
+            player_choice: Choice::Split,
+            opponent_choice: Choice::Steal,
+            outcome_amount: 0,
+            got_split_bonus: false,
+        }
+    }
+
+    fn slots(history: &PlayerHistory) -> Vec<u64> {
+        history.recent().iter().map(|entry| entry.slot).collect()
+    }
+
+    #[test]
+    fn test_history_ring() {
+        let mut history = PlayerHistory {
+            bump: 0,
+            player: Pubkey::default(),
+            capacity: 3,
+            next_index: 0,
+            entries: Vec::new(),
+        };
+        history.record(entry(1));
+        history.record(entry(2));
+        assert_eq!(slots(&history), vec![1, 2]);
+
+        for slot in 3..=5 {
+            history.record(entry(slot));
+        }
+        assert_eq!(history.entries.len(), 3);
+        assert_eq!(slots(&history), vec![3, 4, 5]);
+
+        // Shrinking keeps the newest games
+        history.resize(2).unwrap();
+        assert_eq!(slots(&history), vec![4, 5]);
+        history.record(entry(6));
+        assert_eq!(slots(&history), vec![5, 6]);
+
+        // Growing keeps everything and fills up again before wrapping
+        history.resize(4).unwrap();
+        history.record(entry(7));
+        history.record(entry(8));
+        history.record(entry(9));
+        assert_eq!(slots(&history), vec![6, 7, 8, 9]);
+
+        history.resize(0).unwrap();
+        history.record(entry(10));
+        assert!(history.recent().is_empty());
+
+        assert!(history.resize(MAX_PLAYER_HISTORY_LEN + 1).is_err());
+    }
+
+    #[test]
+    fn test_space_fits_full_history() {
+        let history = PlayerHistory {
+            bump: 0,
+            player: Pubkey::default(),
+            capacity: 5,
+            next_index: 0,
+            entries: vec![entry(u64::MAX); 5],
+        };
+        let mut data = Vec::new();
+        history.try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), PlayerHistory::space(5));
+    }
+}
@@ -2,18 +2,86 @@ use num::integer::Roots;
 
 use super::*;
 
-// The minimum number of games played to be at least 1% statistically likely to get that number of split bonuses
-const MIN_GAMES: [u32; 93] = [
-    0, 11, 154, 457, 871, 1363, 1912, 2505, 3133, 3789, 4469, 5169, 5886, 6618, 7364, 8122, 8890,
-    9668, 10455, 11250, 12052, 12861, 13676, 14497, 15323, 16154, 16990, 17831, 18675, 19524,
-    20376, 21232, 22091, 22953, 23819, 24687, 25558, 26431, 27307, 28186, 29067, 29950, 30835,
-    31722, 32611, 33502, 34395, 35289, 36186, 37084, 37983, 38884, 39787, 40691, 41596, 42503,
-    43411, 44320, 45231, 46143, 47056, 47970, 48885, 49802, 50719, 51638, 52557, 53477, 54399,
-    55321, 56244, 57169, 58094, 59019, 59946, 60874, 61802, 62731, 63661, 64591, 65523, 66455,
-    67388, 68321, 69255, 70190, 71125, 72062, 72998, 73936, 74873, 75812, 76751,
-];
+// First failed burn rate check locks the profile out for roughly a minute, doubling with every failure after
+pub const BURN_RATE_LOCKOUT_SLOTS: u64 = 150;
+// Caps the doubling at about six months of slots
+const MAX_LOCKOUT_DOUBLINGS: u32 = 18;
+// Fewest games that can pass the significance test, needs the opponent splitting exactly half the time
+pub const MIN_SIGNIFICANT_GAMES: u32 = 10;
+
+// Reset whenever the player first plays in a new season, lifetime totals live on the profile itself
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct PlayerSeasonStats {
+    pub season: u32,
+    pub num_games: u32,
+    pub tokens_gained: u64,
+    pub tokens_burned: u64,
+    pub num_split_bonuses: u32,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct OutcomeCell {
+    pub wins: u32,
+    pub losses: u32,
+}
+
+// The player's own choices and results, a win is any game that paid out
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct PlayerStats {
+    pub num_splits: u32,
+    pub num_steals: u32,
+    pub current_split_streak: u32,
+    // Cells of the payoff matrix, player's choice first then the opponent's
+    pub split_split: OutcomeCell,
+    pub split_steal: OutcomeCell,
+    pub steal_split: OutcomeCell,
+    pub steal_steal: OutcomeCell,
+    pub current_win_streak: u32,
+    pub best_win_streak: u32,
+    pub largest_payout: u64,
+    // Whole lost stake, including any share sent to the jackpot
+    pub largest_burn: u64,
+}
+
+impl PlayerStats {
+    pub fn record_game(
+        &mut self,
+        player_choice: Choice,
+        opponent_choice: Choice,
+        outcome_amount: u64,
+        lost_amount: u64,
+    ) {
+        let cell = match (player_choice, opponent_choice) {
+            (Choice::Split, Choice::Split) => &mut self.split_split,
+            (Choice::Split, Choice::Steal) => &mut self.split_steal,
+            (Choice::Steal, Choice::Split) => &mut self.steal_split,
+            (Choice::Steal, Choice::Steal) => &mut self.steal_steal,
+        };
+        if outcome_amount > 0 {
+            cell.wins += 1;
+            self.current_win_streak += 1;
+            self.best_win_streak = self.best_win_streak.max(self.current_win_streak);
+        } else {
+            cell.losses += 1;
+            self.current_win_streak = 0;
+        }
+        match player_choice {
+            Choice::Split => {
+                self.num_splits += 1;
+                self.current_split_streak += 1;
+            }
+            Choice::Steal => {
+                self.num_steals += 1;
+                self.current_split_streak = 0;
+            }
+        }
+        self.largest_payout = self.largest_payout.max(outcome_amount);
+        self.largest_burn = self.largest_burn.max(lost_amount);
+    }
+}
 
 #[account]
+#[derive(Default)]
 pub struct PlayerProfile {
     pub is_initialized: bool,
     pub bump: u8,
@@ -21,14 +89,75 @@ pub struct PlayerProfile {
     pub tokens_gained: u64,
     pub tokens_burned: u64,
     pub num_games: u32,
-    pub num_split_bonuses: u8,
+    pub num_split_bonuses: u32,
     // Between 0 and 1000 with 9 decimal places of accuracy
     pub average_num_splits: u64,
     // Between 0 and 100 with 9 decimal places of accuracy
     pub average_stake_amount: u64,
+    // Default pubkey when the player signed up without a referrer
+    pub referrer: Pubkey,
+    pub num_referrals: u32,
+    pub referral_lamports_earned: u64,
+    pub referral_tokens_earned: u64,
+    pub season_stats: PlayerSeasonStats,
+    // Default pubkey when not in a team
+    pub team: Pubkey,
+    pub team_changed_slot: u64,
+    pub burn_rate_check_failures: u32,
+    // Slot of the most recent failed burn rate check, zero if never flagged
+    pub flagged_slot: u64,
+    pub locked_until_slot: u64,
+    // Running expected vs actual payout, only counts games since tracking was added and leaves out split bonuses
+    pub luck_expected_gained: u64,
+    pub luck_actual_gained: u64,
+    // Sum of the per game payout variances, in base units squared
+    pub luck_variance: u128,
+    // Games the three above cover, num_games also counts games from before tracking
+    pub luck_num_games: u32,
+    pub luck_sprt: LuckSprt,
+    // Profile fee held on the profile instead of paid to the treasury, refunded to the depositor by claim_deposit
+    pub deposit_lamports: u64,
+    pub depositor: Pubkey,
+    pub stats: PlayerStats,
+    // Bits from Achievement::flag, claimed ones have had their badge minted by claim_achievement
+    pub achievements: u32,
+    pub claimed_achievements: u32,
+    // Set once this wallet is linked to a primary wallet with link_wallet, games are then played on the primary's profile.
+    // The wallet rather than the profile address so the link survives migrate_profile
+    pub primary_wallet: Pubkey,
+    // Wallets linked to this profile as their primary
+    pub num_linked_wallets: u32,
+}
+
+// The profile as originally deployed, seeded by the player alone. Read by migrate_profile only
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct LegacyPlayerProfile {
+    pub is_initialized: bool,
+    pub bump: u8,
+    pub last_played_slot: u64,
+    pub tokens_gained: u64,
+    pub tokens_burned: u64,
+    pub num_games: u32,
+    pub num_split_bonuses: u8,
+    pub average_num_splits: u64,
+    pub average_stake_amount: u64,
 }
 
 impl PlayerProfile {
+    // Keeps the lifetime totals and averages, everything added since starts off like a fresh profile
+    pub fn from_legacy(legacy: &LegacyPlayerProfile, bump: u8) -> Self {
+        let mut profile = PlayerProfile::default();
+        profile.initialize(bump);
+        profile.last_played_slot = legacy.last_played_slot;
+        profile.tokens_gained = legacy.tokens_gained;
+        profile.tokens_burned = legacy.tokens_burned;
+        profile.num_games = legacy.num_games;
+        profile.num_split_bonuses = legacy.num_split_bonuses as u32;
+        profile.average_num_splits = legacy.average_num_splits;
+        profile.average_stake_amount = legacy.average_stake_amount;
+        profile
+    }
+
     pub fn initialize(&mut self, bump: u8) {
         self.is_initialized = true;
         self.bump = bump;
@@ -37,15 +166,142 @@ impl PlayerProfile {
         self.tokens_burned = 0;
     }
 
-    pub fn can_get_split_bonus(&self) -> bool {
-        // Have they done enough games to be at least 1% minimum likelihood of getting another split bonus
+    pub fn record_season_game(
+        &mut self,
+        current_season: u32,
+        tokens_gained: u64,
+        tokens_burned: u64,
+        got_split_bonus: bool,
+    ) {
+        if self.season_stats.season != current_season {
+            self.season_stats = PlayerSeasonStats {
+                season: current_season,
+                ..Default::default()
+            };
+        }
+        let stats = &mut self.season_stats;
+        stats.num_games += 1;
+        stats.tokens_gained += tokens_gained;
+        stats.tokens_burned += tokens_burned;
+        if got_split_bonus {
+            stats.num_split_bonuses = stats.num_split_bonuses.saturating_add(1);
+        }
+    }
+
+    pub fn has_achievement(&self, achievement: Achievement) -> bool {
+        self.achievements & achievement.flag() != 0
+    }
+
+    // Call after the game is recorded in the stats, returns the flags of any newly earned achievements
+    pub fn record_achievements(
+        &mut self,
+        num_splits: u16,
+        outcome_amount: u64,
+        got_split_bonus: bool,
+    ) -> u32 {
+        let before = self.achievements;
+        let earned = [
+            (Achievement::FirstSplitBonus, got_split_bonus),
+            (
+                Achievement::ThousandGames,
+                self.num_games >= ACHIEVEMENT_NUM_GAMES,
+            ),
+            (
+                Achievement::TenSplitsInARow,
+                self.stats.current_split_streak >= ACHIEVEMENT_SPLIT_STREAK,
+            ),
+            (
+                Achievement::SurvivedStealHeavyVault,
+                num_splits <= ACHIEVEMENT_STEAL_HEAVY_NUM_SPLITS && outcome_amount > 0,
+            ),
+        ];
+        for (achievement, earned) in earned {
+            if earned {
+                self.achievements |= achievement.flag();
+            }
+        }
+        self.achievements & !before
+    }
+
+    pub fn is_linked(&self) -> bool {
+        self.primary_wallet != Pubkey::default()
+    }
+
+    // Folds a linked wallet's luck statistics in, so linking can't be used to start them over
+    pub fn merge_luck_stats(&mut self, other: &PlayerProfile) {
+        let num_games = self.num_games as u128;
+        let other_num_games = other.num_games as u128;
+        // Weighted by each profile's games, stays put when neither has played
+        let merge_average = |average: u64, other_average: u64| {
+            (average as u128 * num_games + other_average as u128 * other_num_games)
+                .checked_div(num_games + other_num_games)
+                .map_or(average, |merged| merged as u64)
+        };
+        self.average_num_splits = merge_average(self.average_num_splits, other.average_num_splits);
+        self.average_stake_amount =
+            merge_average(self.average_stake_amount, other.average_stake_amount);
+        self.num_games += other.num_games;
+        self.tokens_gained += other.tokens_gained;
+        self.tokens_burned += other.tokens_burned;
+        self.num_split_bonuses += other.num_split_bonuses;
+
+        self.luck_expected_gained += other.luck_expected_gained;
+        self.luck_actual_gained += other.luck_actual_gained;
+        self.luck_variance += other.luck_variance;
+        self.luck_num_games += other.luck_num_games;
+        // The games are independent so the log likelihood ratios add up
+        self.luck_sprt.log_likelihood_ratio += other.luck_sprt.log_likelihood_ratio;
+        self.luck_sprt.num_games += other.luck_sprt.num_games;
+        self.luck_sprt.num_restarts += other.luck_sprt.num_restarts;
+
+        self.burn_rate_check_failures += other.burn_rate_check_failures;
+        self.flagged_slot = self.flagged_slot.max(other.flagged_slot);
+        self.locked_until_slot = self.locked_until_slot.max(other.locked_until_slot);
+        self.last_played_slot = self.last_played_slot.max(other.last_played_slot);
+    }
+
+    pub fn can_change_team(&self, current_slot: u64) -> bool {
+        // Never changed team before so no cooldown
+        self.team_changed_slot == 0
+            || current_slot >= self.team_changed_slot + TEAM_CHANGE_COOLDOWN_SLOTS
+    }
+
+    pub fn is_locked_out(&self, current_slot: u64) -> bool {
+        current_slot < self.locked_until_slot
+    }
+
+    // Returns the slot the lockout ends. A failed SPRT is over, the player starts a new one once the lockout is served,
+    // otherwise its log likelihood ratio would stay over the boundary and fail every game after
+    pub fn record_failed_burn_rate_check(
+        &mut self,
+        current_slot: u64,
+        failed_sprt_check: bool,
+    ) -> u64 {
+        if failed_sprt_check {
+            self.luck_sprt.restart();
+        }
+        let doublings = self.burn_rate_check_failures.min(MAX_LOCKOUT_DOUBLINGS);
+        self.burn_rate_check_failures += 1;
+        self.flagged_slot = current_slot;
+        self.locked_until_slot = current_slot + (BURN_RATE_LOCKOUT_SLOTS << doublings);
+        self.locked_until_slot
+    }
+
+    pub fn can_get_split_bonus(&self, confidence_bps: u16) -> bool {
+        // Have they done enough games to be at least 1% (at the default 99% confidence) likely to get another split bonus
         // e.g. <1% chance you'll get a split bonus in your first 10 games
-        let num_bonuses_idx = (self.num_split_bonuses + 1) as usize;
-        if num_bonuses_idx > MIN_GAMES.len() {
+        let num_games = self.num_games as u64;
+        let next_num_bonuses = self.num_split_bonuses as u64 + 1;
+        // The chance of exactly k bonuses only grows until 1000k games, past that k bonuses isn't lucky at all
+        if num_games >= next_num_bonuses * 1_000 {
+            return true;
+        }
+        // Too many bonuses to work out the exact chance cheaply, only the shortcut above applies
+        if next_num_bonuses > MAX_SPLIT_BONUS_PROBABILITY_TERMS {
             return false;
         }
-        // If someone gets more than 92 split bonuses they can create a new account. Takes almost 80,000 plays though
-        self.num_games >= MIN_GAMES[num_bonuses_idx]
+        let min_probability = (MAX_BASIS_POINTS - confidence_bps) as u64 * 100_000;
+        split_bonus_probability(num_games, next_num_bonuses) >= min_probability
     }
 
     // These should be called after the number of games is updated
@@ -98,17 +354,58 @@ impl PlayerProfile {
         scaled_expected_value.saturating_sub((scaled_std_dev * z_score) / 1e9 as u128)
     }
 
-    fn passes_significance_test(&self) -> bool {
+    // Payout if the opponent splits, which happens num_splits / 1000 of the time
+    pub fn record_expected_payout(
+        &mut self,
+        payout_value: u64,
+        num_splits: u16,
+        actual_payout: u64,
+    ) {
+        let value = payout_value as u128;
+        let num_splits = num_splits as u128;
+        self.luck_expected_gained += (value * num_splits / 1_000) as u64;
+        self.luck_variance += value * value * num_splits * (1_000 - num_splits) / 1_000_000;
+        self.luck_actual_gained += actual_payout;
+        self.luck_num_games += 1;
+    }
+
+    fn calculate_winnings_threshold(&self) -> u128 {
+        let std_dev = self.luck_variance.sqrt();
+        // Same 2.33 z score as the burn rate check
+        let z_score = 2_330 * 1e6 as u128;
+        self.luck_expected_gained as u128 + (std_dev * z_score) / 1e9 as u128
+    }
+
+    // Takes the number of games the check being made covers, lifetime or only the tracked ones
+    fn passes_significance_test(&self, num_games: u32) -> bool {
         let prob = (self.average_num_splits / 1_000) as u128;
         let one_minus_prob = (1e9 as u64 - self.average_num_splits / 1_000) as u128;
 
-        self.num_games as u128 * prob >= 5 * 1e9 as u128
-            && self.num_games as u128 * one_minus_prob >= 5 * 1e9 as u128
+        num_games as u128 * prob >= 5 * 1e9 as u128
+            && num_games as u128 * one_minus_prob >= 5 * 1e9 as u128
+    }
+
+    pub fn passes_winnings_check(&self) -> bool {
+        // Gained more than the 99% upper bound on payouts
+        !(self.passes_significance_test(self.luck_num_games)
+            && self.luck_actual_gained as u128 > self.calculate_winnings_threshold())
+    }
+
+    // Enough games played without ever tripping a luck check
+    // The luck checks pass trivially until the significance test does, so that has to pass too
+    pub fn can_claim_deposit(&self, config: &VaultConfig) -> bool {
+        self.deposit_lamports > 0
+            && self.num_games >= config.deposit_refund_min_games
+            && self.passes_significance_test(self.num_games)
+            && self.burn_rate_check_failures == 0
+            && self.passes_burn_rate_check()
+            && self.passes_winnings_check()
+            && (!config.sprt_enabled() || self.luck_sprt.passes(config))
     }
 
     pub fn passes_burn_rate_check(&self) -> bool {
         // Does it pass the minimum observations for significance
-        if self.passes_significance_test() {
+        if self.passes_significance_test(self.num_games) {
             // Does it pass the 99% threshold for minimum expected burn
             if (self.tokens_burned as u128) < self.calculate_99_percent_threshold() {
                 return false;
@@ -121,6 +418,18 @@ impl PlayerProfile {
 mod tests {
     use super::*;
 
+    // The table can_get_split_bonus used to read from, the minimum number of games played to be at least
+    // 1% statistically likely to get exactly that number of split bonuses
+    const MIN_GAMES: [u32; 93] = [
+        0, 11, 154, 457, 871, 1363, 1912, 2505, 3133, 3789, 4469, 5169, 5886, 6618, 7364, 8122,
+        8890, 9668, 10455, 11250, 12052, 12861, 13676, 14497, 15323, 16154, 16990, 17831, 18675,
+        19524, 20376, 21232, 22091, 22953, 23819, 24687, 25558, 26431, 27307, 28186, 29067, 29950,
+        30835, 31722, 32611, 33502, 34395, 35289, 36186, 37084, 37983, 38884, 39787, 40691, 41596,
+        42503, 43411, 44320, 45231, 46143, 47056, 47970, 48885, 49802, 50719, 51638, 52557, 53477,
+        54399, 55321, 56244, 57169, 58094, 59019, 59946, 60874, 61802, 62731, 63661, 64591, 65523,
+        66455, 67388, 68321, 69255, 70190, 71125, 72062, 72998, 73936, 74873, 75812, 76751,
+    ];
+
     #[test]
     fn test_significance_test() {
         let profile_significant = PlayerProfile {
@@ -133,6 +442,7 @@ mod tests {
             num_split_bonuses: 2,
             average_num_splits: 100_000_000_000, // 100
             average_stake_amount: 1_000_000_000, // 1.0
+            ..Default::default()
         };
 
         let profile_not_significant = PlayerProfile {
@@ -145,10 +455,13 @@ mod tests {
             num_split_bonuses: 2,
             average_num_splits: 100_000_000_000, // 100
             average_stake_amount: 1_000_000_000, // 1.0
+            ..Default::default()
         };
 
-        assert!(profile_significant.passes_significance_test());
-        assert!(!profile_not_significant.passes_significance_test());
+        assert!(profile_significant.passes_significance_test(profile_significant.num_games));
+        assert!(
+            !profile_not_significant.passes_significance_test(profile_not_significant.num_games)
+        );
     }
 
     // Helper function to calculate 99% threshold using f64
@@ -177,9 +490,10 @@ mod tests {
             num_split_bonuses: 1,
             average_num_splits: 100_000_000_000, // 100
             average_stake_amount: 1_000_000_000, // 1.0
+            ..Default::default()
         };
 
-        assert!(profile.can_get_split_bonus());
+        assert!(profile.can_get_split_bonus(DEFAULT_SPLIT_BONUS_CONFIDENCE_BPS));
     }
 
     #[test]
@@ -194,9 +508,10 @@ mod tests {
             num_split_bonuses: 1,
             average_num_splits: 100_000_000_000, // 100
             average_stake_amount: 1_000_000_000, // 1.0
+            ..Default::default()
         };
 
-        assert!(!profile.can_get_split_bonus());
+        assert!(!profile.can_get_split_bonus(DEFAULT_SPLIT_BONUS_CONFIDENCE_BPS));
     }
 
     #[test]
@@ -211,6 +526,7 @@ mod tests {
             num_split_bonuses: 2,
             average_num_splits: 100_000_000_000, // 100 10%
             average_stake_amount: 1_000_000_000, // 1.0
+            ..Default::default()
         };
 
         // Using f64 to verify
@@ -239,6 +555,7 @@ mod tests {
             num_split_bonuses: 2,
             average_num_splits: 500_000_000_000, // 500 50% split chance
             average_stake_amount: 1_000_000_000, // 1.0
+            ..Default::default()
         };
 
         // Using f64 to verify
@@ -267,6 +584,7 @@ mod tests {
             num_split_bonuses: 50,
             average_num_splits: 500_000_000_000,  // 500
             average_stake_amount: 10_000_000_000, // 10.0
+            ..Default::default()
         };
 
         // Using f64 to verify
@@ -295,6 +613,7 @@ mod tests {
             num_split_bonuses: 50,
             average_num_splits: 500_000_000_000,  // 500
             average_stake_amount: 10_000_000_000, // 10.0
+            ..Default::default()
         };
 
         // Using f64 to verify
@@ -324,8 +643,9 @@ mod tests {
             num_split_bonuses: 0,
             average_num_splits: 0,
             average_stake_amount: 0,
+            ..Default::default()
         };
-        assert!(!profile.can_get_split_bonus());
+        assert!(!profile.can_get_split_bonus(DEFAULT_SPLIT_BONUS_CONFIDENCE_BPS));
         assert!(profile.passes_burn_rate_check());
     }
 
@@ -341,8 +661,10 @@ mod tests {
             num_split_bonuses: 100,
             average_num_splits: 1_000_000_000_000, // 1000 splits
             average_stake_amount: 100_000_000_000, // 100 stake amount
+            ..Default::default()
         };
-        assert!(!profile.can_get_split_bonus()); // Should fail as max bonuses exceeded
+        // Past the old table's 92 bonuses, 101 bonuses in 100,000 games is no longer ruled out
+        assert!(profile.can_get_split_bonus(DEFAULT_SPLIT_BONUS_CONFIDENCE_BPS));
         assert!(profile.passes_burn_rate_check());
     }
 
@@ -359,6 +681,7 @@ mod tests {
             num_split_bonuses: 0,
             average_num_splits: 100_000_000_000, // 100
             average_stake_amount: 1_000_000_000, // 1.0
+            ..Default::default()
         };
         profile.update_average_num_splits(200);
         assert_eq!(profile.average_num_splits, 150_000_000_000); // Should be the average of 100 and 200
@@ -376,6 +699,7 @@ mod tests {
             num_split_bonuses: 0,
             average_num_splits: 100_000_000_000, // 100
             average_stake_amount: 1_000_000_000, // 1.0
+            ..Default::default()
         };
         profile.update_average_stake_amount(2);
         assert_eq!(profile.average_stake_amount, 1_500_000_000); // Should be the average of 1.0 and 2.0
@@ -395,6 +719,7 @@ mod tests {
             num_split_bonuses: 0,
             average_num_splits: 0,
             average_stake_amount: 0,
+            ..Default::default()
         };
         profile.update_average_num_splits(200);
         assert_eq!(profile.average_num_splits, 0); // Should remain 0 as num_games is 0
@@ -413,6 +738,7 @@ mod tests {
             num_split_bonuses: 0,
             average_num_splits: 0,
             average_stake_amount: 0,
+            ..Default::default()
         };
         profile.update_average_stake_amount(200);
         assert_eq!(profile.average_stake_amount, 0); // Should remain 0 as num_games is 0
@@ -431,11 +757,12 @@ mod tests {
             num_split_bonuses: 0,
             average_num_splits: 100_000_000_000, // 100
             average_stake_amount: 1_000_000_000, // 1.0
+            ..Default::default()
         };
-        assert!(!profile.can_get_split_bonus());
+        assert!(!profile.can_get_split_bonus(DEFAULT_SPLIT_BONUS_CONFIDENCE_BPS));
 
         profile.num_games = 11;
-        assert!(profile.can_get_split_bonus());
+        assert!(profile.can_get_split_bonus(DEFAULT_SPLIT_BONUS_CONFIDENCE_BPS));
     }
 
     // Randomized tests
@@ -461,6 +788,7 @@ mod tests {
                 num_split_bonuses,
                 average_num_splits,
                 average_stake_amount,
+                ..Default::default()
             };
 
             let threshold_f64 = calculate_99_percent_threshold_f64(
@@ -490,7 +818,10 @@ mod tests {
                 false
             };
 
-            assert_eq!(profile.can_get_split_bonus(), passes_bonus_check);
+            assert_eq!(
+                profile.can_get_split_bonus(DEFAULT_SPLIT_BONUS_CONFIDENCE_BPS),
+                passes_bonus_check
+            );
 
             let passes_burn_rate_check = profile.passes_burn_rate_check();
             let tokens_burned_f64 = profile.tokens_burned as f64 / 1e9;
@@ -499,6 +830,451 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_season_stats_reset_on_new_season() {
+        let mut profile = PlayerProfile {
+            is_initialized: true,
+            num_games: 3,
+            tokens_gained: 5_000_000_000,
+            ..Default::default()
+        };
+
+        profile.record_season_game(0, 2_000_000_000, 0, true);
+        profile.record_season_game(0, 0, 1_000_000_000, false);
+        assert_eq!(profile.season_stats.num_games, 2);
+        assert_eq!(profile.season_stats.tokens_gained, 2_000_000_000);
+        assert_eq!(profile.season_stats.tokens_burned, 1_000_000_000);
+        assert_eq!(profile.season_stats.num_split_bonuses, 1);
+
+        // First game of the next season starts the counters over
+        profile.record_season_game(1, 3_000_000_000, 0, false);
+        assert_eq!(profile.season_stats.season, 1);
+        assert_eq!(profile.season_stats.num_games, 1);
+        assert_eq!(profile.season_stats.tokens_gained, 3_000_000_000);
+        assert_eq!(profile.season_stats.tokens_burned, 0);
+        assert_eq!(profile.season_stats.num_split_bonuses, 0);
+
+        // Lifetime totals are left alone
+        assert_eq!(profile.num_games, 3);
+        assert_eq!(profile.tokens_gained, 5_000_000_000);
+    }
+
+    #[test]
+    fn test_team_change_cooldown() {
+        let mut profile = PlayerProfile {
+            is_initialized: true,
+            ..Default::default()
+        };
+        assert!(profile.can_change_team(0));
+
+        profile.team_changed_slot = 1_000;
+        assert!(!profile.can_change_team(1_000));
+        assert!(!profile.can_change_team(1_000 + TEAM_CHANGE_COOLDOWN_SLOTS - 1));
+        assert!(profile.can_change_team(1_000 + TEAM_CHANGE_COOLDOWN_SLOTS));
+    }
+
+    fn calculate_winnings_threshold_f64(payouts: &[(f64, f64)]) -> f64 {
+        let expected: f64 = payouts.iter().map(|(value, prob)| value * prob).sum();
+        let variance: f64 = payouts
+            .iter()
+            .map(|(value, prob)| value * value * prob * (1.0 - prob))
+            .sum();
+        expected + 2.33 * variance.sqrt()
+    }
+
+    #[test]
+    fn test_winnings_check() {
+        use rand::Rng;
+
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let mut profile = PlayerProfile {
+                is_initialized: true,
+                luck_num_games: 1_000,
+                average_num_splits: 500_000_000_000, // 500
+                ..Default::default()
+            };
+            let mut payouts = Vec::new();
+            for _ in 0..rng.gen_range(1..200) {
+                let payout_value = rng.gen_range(0..200_000_000_000u64);
+                let num_splits = rng.gen_range(1..1_000u16);
+                profile.record_expected_payout(payout_value, num_splits, 0);
+                payouts.push((payout_value as f64 / 1e9, num_splits as f64 / 1000.0));
+            }
+
+            let threshold_f64 = calculate_winnings_threshold_f64(&payouts);
+            let threshold_u128_as_f64 = profile.calculate_winnings_threshold() as f64 / 1e9;
+            assert!(
+                (threshold_u128_as_f64 - threshold_f64).abs() <= 0.01,
+                "u128 threshold as f64: {}, f64 threshold: {}",
+                threshold_u128_as_f64,
+                threshold_f64
+            );
+
+            // Just under and just over the bound
+            profile.luck_actual_gained = (threshold_f64 * 0.99 * 1e9) as u64;
+            assert!(profile.passes_winnings_check());
+            profile.luck_actual_gained = (threshold_f64 * 1.01 * 1e9) as u64 + 1;
+            assert!(!profile.passes_winnings_check());
+        }
+    }
+
+    #[test]
+    fn test_winnings_check_needs_significance() {
+        let mut profile = PlayerProfile {
+            is_initialized: true,
+            num_games: 1,
+            average_num_splits: 500_000_000_000, // 500
+            ..Default::default()
+        };
+        // One huge lucky win straight away isn't enough to flag anyone
+        profile.record_expected_payout(100_000_000_000, 100, 100_000_000_000);
+        assert!(profile.passes_winnings_check());
+
+        profile.luck_num_games = 1_000;
+        assert!(!profile.passes_winnings_check());
+    }
+
+    #[test]
+    fn test_migrated_profile_first_win() {
+        let legacy = LegacyPlayerProfile {
+            is_initialized: true,
+            bump: 255,
+            last_played_slot: 1_000,
+            tokens_gained: 1_000_000_000_000,
+            tokens_burned: 9_000_000_000_000, // Right on the expected burn for 1000 games of 10 tokens
+            num_games: 1_000,
+            num_split_bonuses: 0,
+            average_num_splits: 100_000_000_000, // 100
+            average_stake_amount: 10_000_000_000,
+        };
+        let mut profile = PlayerProfile::from_legacy(&legacy, 254);
+        // Against a steal heavy vault one win is way over the bound on the expected payout,
+        // but it's the only game the payouts have been tracked for
+        profile.record_expected_payout(10_000_000_000, 100, 10_000_000_000);
+        assert_eq!(profile.luck_num_games, 1);
+        assert!(profile.passes_winnings_check());
+        assert!(profile.passes_burn_rate_check());
+    }
+
+    #[test]
+    fn test_split_bonus_bound_matches_table() {
+        for (num_bonuses, &min_games) in MIN_GAMES.iter().enumerate().skip(1) {
+            let mut profile = PlayerProfile {
+                is_initialized: true,
+                num_games: min_games,
+                num_split_bonuses: num_bonuses as u32 - 1,
+                ..Default::default()
+            };
+            assert!(profile.can_get_split_bonus(DEFAULT_SPLIT_BONUS_CONFIDENCE_BPS));
+            profile.num_games = min_games - 1;
+            assert!(
+                !profile.can_get_split_bonus(DEFAULT_SPLIT_BONUS_CONFIDENCE_BPS),
+                "{} bonuses should need {} games",
+                num_bonuses,
+                min_games
+            );
+        }
+    }
+
+    #[test]
+    fn test_split_bonus_bound_past_table() {
+        let mut profile = PlayerProfile {
+            is_initialized: true,
+            num_games: 76_751,
+            num_split_bonuses: 200,
+            ..Default::default()
+        };
+        assert!(!profile.can_get_split_bonus(DEFAULT_SPLIT_BONUS_CONFIDENCE_BPS));
+
+        // Always eligible once the bonus count is at or under what's expected
+        profile.num_games = 201_000;
+        assert!(profile.can_get_split_bonus(DEFAULT_SPLIT_BONUS_CONFIDENCE_BPS));
+
+        // Higher confidence only blocks the really unlikely counts, so bonuses come sooner
+        let profile = PlayerProfile {
+            is_initialized: true,
+            num_games: 5,
+            num_split_bonuses: 0,
+            ..Default::default()
+        };
+        assert!(!profile.can_get_split_bonus(9_900));
+        assert!(profile.can_get_split_bonus(9_990));
+    }
+
+    #[test]
+    fn test_split_bonus_bounded_work() {
+        // split_bonus_probability debug asserts it's never asked for more terms than the cap
+        for num_split_bonuses in [
+            MAX_SPLIT_BONUS_PROBABILITY_TERMS as u32 - 1,
+            MAX_SPLIT_BONUS_PROBABILITY_TERMS as u32,
+            100_000,
+            u32::MAX - 1,
+        ] {
+            let next_num_bonuses = num_split_bonuses as u64 + 1;
+            let mut profile = PlayerProfile {
+                is_initialized: true,
+                num_games: (next_num_bonuses * 1_000 - 1).min(u32::MAX as u64) as u32,
+                num_split_bonuses,
+                ..Default::default()
+            };
+            let within_cap = next_num_bonuses <= MAX_SPLIT_BONUS_PROBABILITY_TERMS;
+            assert_eq!(profile.can_get_split_bonus(9_999), within_cap);
+
+            // The shortcut still lets them through once they've played enough
+            if next_num_bonuses * 1_000 <= u32::MAX as u64 {
+                profile.num_games = (next_num_bonuses * 1_000) as u32;
+                assert!(profile.can_get_split_bonus(9_999));
+            }
+        }
+    }
+
+    #[test]
+    fn test_player_stats() {
+        let mut stats = PlayerStats::default();
+        stats.record_game(Choice::Split, Choice::Split, 10, 0);
+        stats.record_game(Choice::Steal, Choice::Split, 30, 0);
+        stats.record_game(Choice::Split, Choice::Split, 5, 0);
+        stats.record_game(Choice::Steal, Choice::Steal, 0, 20);
+        // Opponent split but the player failed a luck check and lost the stake
+        stats.record_game(Choice::Split, Choice::Split, 0, 15);
+        stats.record_game(Choice::Split, Choice::Steal, 0, 0);
+        stats.record_game(Choice::Steal, Choice::Split, 7, 0);
+
+        assert_eq!(stats.num_splits, 4);
+        assert_eq!(stats.num_steals, 3);
+        assert_eq!(stats.split_split, OutcomeCell { wins: 2, losses: 1 });
+        assert_eq!(stats.split_steal, OutcomeCell { wins: 0, losses: 1 });
+        assert_eq!(stats.steal_split, OutcomeCell { wins: 2, losses: 0 });
+        assert_eq!(stats.steal_steal, OutcomeCell { wins: 0, losses: 1 });
+        assert_eq!(stats.current_win_streak, 1);
+        assert_eq!(stats.best_win_streak, 3);
+        assert_eq!(stats.largest_payout, 30);
+        assert_eq!(stats.largest_burn, 20);
+    }
+
+    #[test]
+    fn test_record_achievements() {
+        let mut profile = PlayerProfile {
+            num_games: 10,
+            ..Default::default()
+        };
+        assert_eq!(profile.record_achievements(500, 10, false), 0);
+
+        assert_eq!(
+            profile.record_achievements(500, 10, true),
+            Achievement::FirstSplitBonus.flag()
+        );
+        // Only newly earned ones are returned
+        assert_eq!(profile.record_achievements(500, 10, true), 0);
+
+        // Losing in a steal heavy vault doesn't count
+        assert_eq!(profile.record_achievements(100, 0, false), 0);
+        assert_eq!(
+            profile.record_achievements(100, 10, false),
+            Achievement::SurvivedStealHeavyVault.flag()
+        );
+
+        for _ in 0..ACHIEVEMENT_SPLIT_STREAK {
+            profile
+                .stats
+                .record_game(Choice::Split, Choice::Steal, 0, 0);
+        }
+        profile.num_games = ACHIEVEMENT_NUM_GAMES;
+        assert_eq!(
+            profile.record_achievements(500, 0, false),
+            Achievement::ThousandGames.flag() | Achievement::TenSplitsInARow.flag()
+        );
+        for achievement in Achievement::ALL {
+            assert!(profile.has_achievement(achievement));
+        }
+    }
+
+    #[test]
+    fn test_merge_luck_stats() {
+        let mut primary = PlayerProfile {
+            last_played_slot: 500,
+            tokens_gained: 100,
+            tokens_burned: 50,
+            num_games: 30,
+            num_split_bonuses: 1,
+            average_num_splits: 600_000_000_000,
+            average_stake_amount: 10_000_000_000,
+            luck_variance: 1_000,
+            ..Default::default()
+        };
+        let secondary = PlayerProfile {
+            last_played_slot: 400,
+            tokens_gained: 900,
+            tokens_burned: 10,
+            num_games: 10,
+            num_split_bonuses: 2,
+            average_num_splits: 200_000_000_000,
+            average_stake_amount: 50_000_000_000,
+            luck_variance: 500,
+            burn_rate_check_failures: 2,
+            locked_until_slot: 10_000,
+            ..Default::default()
+        };
+        primary.merge_luck_stats(&secondary);
+
+        assert_eq!(primary.num_games, 40);
+        assert_eq!(primary.tokens_gained, 1_000);
+        assert_eq!(primary.tokens_burned, 60);
+        assert_eq!(primary.num_split_bonuses, 3);
+        assert_eq!(primary.average_num_splits, 500_000_000_000);
+        assert_eq!(primary.average_stake_amount, 20_000_000_000);
+        assert_eq!(primary.luck_variance, 1_500);
+        assert_eq!(primary.burn_rate_check_failures, 2);
+        // The secondary's lockout carries over, the cooldown goes by the latest game
+        assert!(primary.is_locked_out(9_999));
+        assert_eq!(primary.last_played_slot, 500);
+
+        // Merging an empty profile changes nothing
+        let before = primary.clone();
+        primary.merge_luck_stats(&PlayerProfile::default());
+        assert_eq!(primary.average_num_splits, before.average_num_splits);
+        assert_eq!(primary.num_games, before.num_games);
+    }
+
+    #[test]
+    fn test_can_claim_deposit() {
+        let config = VaultConfig {
+            profile_fee_as_deposit: true,
+            deposit_refund_min_games: 100,
+            ..Default::default()
+        };
+        let mut profile = PlayerProfile {
+            is_initialized: true,
+            num_games: 99,
+            deposit_lamports: 6_000_000,
+            average_num_splits: 500_000_000_000,
+            ..Default::default()
+        };
+        assert!(!profile.can_claim_deposit(&config));
+
+        profile.num_games = 100;
+        assert!(profile.can_claim_deposit(&config));
+
+        // Any failed luck check in the past rules the refund out for good
+        profile.burn_rate_check_failures = 1;
+        assert!(!profile.can_claim_deposit(&config));
+
+        // Too few games against such a split heavy vault to say anything about the player's luck
+        profile.burn_rate_check_failures = 0;
+        profile.average_num_splits = 999_000_000_000;
+        assert!(!profile.can_claim_deposit(&config));
+
+        // Nothing to claim once it's been refunded
+        profile.average_num_splits = 500_000_000_000;
+        profile.deposit_lamports = 0;
+        assert!(!profile.can_claim_deposit(&config));
+    }
+
+    #[test]
+    fn test_burn_rate_lockout_escalates() {
+        let mut profile = PlayerProfile {
+            is_initialized: true,
+            ..Default::default()
+        };
+        assert!(!profile.is_locked_out(0));
+
+        assert_eq!(
+            profile.record_failed_burn_rate_check(1_000, false),
+            1_000 + BURN_RATE_LOCKOUT_SLOTS
+        );
+        assert_eq!(profile.burn_rate_check_failures, 1);
+        assert_eq!(profile.flagged_slot, 1_000);
+        assert!(profile.is_locked_out(1_000 + BURN_RATE_LOCKOUT_SLOTS - 1));
+        assert!(!profile.is_locked_out(1_000 + BURN_RATE_LOCKOUT_SLOTS));
+
+        // Every failure after doubles the lockout
+        assert_eq!(
+            profile.record_failed_burn_rate_check(2_000, false),
+            2_000 + 2 * BURN_RATE_LOCKOUT_SLOTS
+        );
+        assert_eq!(
+            profile.record_failed_burn_rate_check(3_000, false),
+            3_000 + 4 * BURN_RATE_LOCKOUT_SLOTS
+        );
+        assert_eq!(profile.flagged_slot, 3_000);
+
+        // Stops growing once it hits the cap
+        profile.burn_rate_check_failures = 100;
+        assert_eq!(
+            profile.record_failed_burn_rate_check(0, false),
+            BURN_RATE_LOCKOUT_SLOTS << MAX_LOCKOUT_DOUBLINGS
+        );
+    }
+
+    #[test]
+    fn test_sprt_recovers_after_lockout() {
+        let config = VaultConfig {
+            sprt_odds_ratio_bps: 20_000,
+            sprt_alpha_bps: 100,
+            sprt_beta_bps: 100,
+            ..Default::default()
+        };
+        let mut profile = PlayerProfile {
+            is_initialized: true,
+            ..Default::default()
+        };
+
+        // Opponent splits every time at 50/50 odds, the test flags them
+        while profile.luck_sprt.passes(&config) {
+            profile.luck_sprt.update(true, 500, &config);
+        }
+        let locked_until_slot = profile.record_failed_burn_rate_check(1_000, true);
+        assert!(profile.is_locked_out(locked_until_slot - 1));
+        assert!(!profile.is_locked_out(locked_until_slot));
+
+        // A new test with half the alpha left, the old evidence doesn't carry over
+        assert_eq!(profile.luck_sprt.log_likelihood_ratio, 0);
+        assert_eq!(profile.luck_sprt.num_restarts, 1);
+        assert!(profile.luck_sprt.passes(&config));
+
+        // Honest play afterwards, half the games won at 50/50 odds, keeps passing
+        for game in 0..200 {
+            profile.luck_sprt.update(game % 2 == 0, 500, &config);
+            assert!(profile.luck_sprt.passes(&config));
+        }
+        assert_eq!(profile.burn_rate_check_failures, 1);
+    }
+
+    #[test]
+    fn test_from_legacy() {
+        let legacy = LegacyPlayerProfile {
+            is_initialized: true,
+            bump: 250,
+            last_played_slot: 1_234,
+            tokens_gained: 5_000_000_000,
+            tokens_burned: 2_000_000_000,
+            num_games: 40,
+            num_split_bonuses: 3,
+            average_num_splits: 600_000_000_000,
+            average_stake_amount: 25_000_000_000,
+        };
+        let data = legacy.try_to_vec().unwrap();
+        // The size of the profile as originally deployed, 55 bytes with the discriminator
+        assert_eq!(data.len(), 47);
+
+        let decoded = LegacyPlayerProfile::deserialize(&mut &data[..]).unwrap();
+        let profile = PlayerProfile::from_legacy(&decoded, 7);
+        assert!(profile.is_initialized);
+        assert_eq!(profile.bump, 7);
+        assert_eq!(profile.last_played_slot, 1_234);
+        assert_eq!(profile.tokens_gained, 5_000_000_000);
+        assert_eq!(profile.tokens_burned, 2_000_000_000);
+        assert_eq!(profile.num_games, 40);
+        assert_eq!(profile.num_split_bonuses, 3);
+        assert_eq!(profile.average_num_splits, 600_000_000_000);
+        assert_eq!(profile.average_stake_amount, 25_000_000_000);
+        assert_eq!(profile.referrer, Pubkey::default());
+        assert_eq!(profile.deposit_lamports, 0);
+        assert_eq!(profile.luck_sprt, LuckSprt::default());
+    }
+
     #[test]
 fn test_specific_numbers() {
     let profile = PlayerProfile {
@@ -511,6 +1287,7 @@ fn test_specific_numbers() {
         num_split_bonuses: 0, // Not relevant for this test
         average_num_splits: 812_000_000_000, // 0.812 split chance
         average_stake_amount: 31_300_000_000, // 31.3 average stake amount
+        ..Default::default()
     };
 
     // Using f64 to verify
@@ -0,0 +1,136 @@
+use super::*;
+
+pub const MIN_NICKNAME_LEN: usize = 3;
+pub const MAX_NICKNAME_LEN: usize = 20;
+pub const MAX_AVATAR_URI_LEN: usize = 128;
+const AVATAR_URI_SCHEMES: [&str; 3] = ["https://", "ipfs://", "ar://"];
+
+// Public profile shown on leaderboards instead of the raw pubkey
+// One per wallet rather than per vault, unlike the stats on the player profile
+#[account]
+pub struct ProfileMetadata {
+    pub bump: u8,
+    pub player: Pubkey,
+    // Zero padded ascii, as the player typed it. The registry holds the normalized form
+    pub nickname: [u8; MAX_NICKNAME_LEN],
+    // Zero padded, empty when the player has no avatar
+    pub avatar_uri: [u8; MAX_AVATAR_URI_LEN],
+    pub show_on_leaderboard: bool,
+}
+
+// One per nickname so no two players can share one, ignoring case. Global like the metadata it belongs to
+#[account]
+pub struct NicknameRecord {
+    pub bump: u8,
+    pub player: Pubkey,
+    pub nickname: [u8; MAX_NICKNAME_LEN],
+}
+
+// Lowercased and zero padded, used as the registry seed. Call validate_nickname first
+pub fn normalize_nickname(nickname: &str) -> [u8; MAX_NICKNAME_LEN] {
+    let mut normalized = [0u8; MAX_NICKNAME_LEN];
+    for (byte, normalized_byte) in nickname.bytes().zip(normalized.iter_mut()) {
+        *normalized_byte = byte.to_ascii_lowercase();
+    }
+    normalized
+}
+
+// Ascii letters, digits, underscores and dashes only, so lookalike unicode can't impersonate anyone
+pub fn validate_nickname(nickname: &str) -> Result<()> {
+    require!(
+        (MIN_NICKNAME_LEN..=MAX_NICKNAME_LEN).contains(&nickname.len())
+            && nickname
+                .bytes()
+                .all(|byte| byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'-'),
+        SplitOrStealError::InvalidNickname
+    );
+    Ok(())
+}
+
+pub fn validate_avatar_uri(avatar_uri: &str) -> Result<()> {
+    require!(
+        avatar_uri.is_empty()
+            || (avatar_uri.len() <= MAX_AVATAR_URI_LEN
+                && AVATAR_URI_SCHEMES
+                    .iter()
+                    .any(|scheme| avatar_uri.starts_with(scheme))),
+        SplitOrStealError::InvalidAvatarUri
+    );
+    Ok(())
+}
+
+impl ProfileMetadata {
+    pub fn set(
+        &mut self,
+        nickname: &str,
+        avatar_uri: &str,
+        show_on_leaderboard: bool,
+    ) -> Result<()> {
+        validate_nickname(nickname)?;
+        validate_avatar_uri(avatar_uri)?;
+
+        self.nickname = [0u8; MAX_NICKNAME_LEN];
+        self.nickname[..nickname.len()].copy_from_slice(nickname.as_bytes());
+        self.avatar_uri = [0u8; MAX_AVATAR_URI_LEN];
+        self.avatar_uri[..avatar_uri.len()].copy_from_slice(avatar_uri.as_bytes());
+        self.show_on_leaderboard = show_on_leaderboard;
+        Ok(())
+    }
+
+    // The stored nickname is already validated ascii
+    pub fn normalized_nickname(&self) -> [u8; MAX_NICKNAME_LEN] {
+        self.nickname.map(|byte| byte.to_ascii_lowercase())
+    }
+
+    pub fn has_nickname(&self) -> bool {
+        self.nickname[0] != 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_nickname() {
+        assert!(validate_nickname("gourd").is_ok());
+        assert!(validate_nickname("Lord_Of-The_Gourd_42").is_ok());
+        assert!(validate_nickname("ab").is_err());
+        assert!(validate_nickname("this_nickname_is_too_long").is_err());
+        assert!(validate_nickname("no spaces").is_err());
+        assert!(validate_nickname("gоurd").is_err()); // Cyrillic o
+    }
+
+    #[test]
+    fn test_normalize_nickname() {
+        assert_eq!(normalize_nickname("Gourd"), normalize_nickname("gOURD"));
+        assert_ne!(normalize_nickname("gourd"), normalize_nickname("gourds"));
+        assert_eq!(&normalize_nickname("AbC")[..4], b"abc\0");
+    }
+
+    #[test]
+    fn test_set_metadata() {
+        let mut metadata = ProfileMetadata {
+            bump: 0,
+            player: Pubkey::default(),
+            nickname: [0; MAX_NICKNAME_LEN],
+            avatar_uri: [0; MAX_AVATAR_URI_LEN],
+            show_on_leaderboard: false,
+        };
+        assert!(!metadata.has_nickname());
+
+        metadata
+            .set("LongerName", "https://example.com/a.png", true)
+            .unwrap();
+        metadata.set("Gourd", "", false).unwrap();
+        // Shorter values don't leave the old ones behind
+        assert_eq!(&metadata.nickname[..6], b"Gourd\0");
+        assert_eq!(metadata.avatar_uri, [0; MAX_AVATAR_URI_LEN]);
+        assert!(metadata.has_nickname());
+        assert_eq!(metadata.normalized_nickname(), normalize_nickname("gourd"));
+
+        assert!(metadata.set("Gourd", "javascript:alert(1)", true).is_err());
+        let too_long = format!("https://{}", "a".repeat(MAX_AVATAR_URI_LEN));
+        assert!(metadata.set("Gourd", &too_long, true).is_err());
+    }
+}
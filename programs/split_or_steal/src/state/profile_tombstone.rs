@@ -0,0 +1,128 @@
+use super::*;
+
+// Left behind by close_profile so a re-created profile picks up where the closed one left off,
+// otherwise closing would wipe the luck checks and split bonus history for the price of the rent
+#[account]
+#[derive(Default)]
+pub struct ProfileTombstone {
+    pub bump: u8,
+    pub num_closes: u32,
+    pub last_played_slot: u64,
+    pub tokens_gained: u64,
+    pub tokens_burned: u64,
+    pub num_games: u32,
+    pub num_split_bonuses: u32,
+    pub average_num_splits: u64,
+    pub average_stake_amount: u64,
+    pub season_stats: PlayerSeasonStats,
+    pub burn_rate_check_failures: u32,
+    pub flagged_slot: u64,
+    pub locked_until_slot: u64,
+    pub luck_expected_gained: u64,
+    pub luck_actual_gained: u64,
+    pub luck_variance: u128,
+    pub luck_num_games: u32,
+    pub luck_sprt: LuckSprt,
+    pub stats: PlayerStats,
+    pub achievements: u32,
+    pub claimed_achievements: u32,
+}
+
+impl ProfileTombstone {
+    pub fn record(&mut self, profile: &PlayerProfile) {
+        self.num_closes += 1;
+        self.last_played_slot = profile.last_played_slot;
+        self.tokens_gained = profile.tokens_gained;
+        self.tokens_burned = profile.tokens_burned;
+        self.num_games = profile.num_games;
+        self.num_split_bonuses = profile.num_split_bonuses;
+        self.average_num_splits = profile.average_num_splits;
+        self.average_stake_amount = profile.average_stake_amount;
+        self.season_stats = profile.season_stats;
+        self.burn_rate_check_failures = profile.burn_rate_check_failures;
+        self.flagged_slot = profile.flagged_slot;
+        self.locked_until_slot = profile.locked_until_slot;
+        self.luck_expected_gained = profile.luck_expected_gained;
+        self.luck_actual_gained = profile.luck_actual_gained;
+        self.luck_variance = profile.luck_variance;
+        self.luck_num_games = profile.luck_num_games;
+        self.luck_sprt = profile.luck_sprt;
+        self.stats = profile.stats;
+        self.achievements = profile.achievements;
+        self.claimed_achievements = profile.claimed_achievements;
+    }
+
+    // Called on a freshly initialized profile, referrals and team membership start over
+    pub fn restore(&self, profile: &mut PlayerProfile) {
+        profile.last_played_slot = self.last_played_slot;
+        profile.tokens_gained = self.tokens_gained;
+        profile.tokens_burned = self.tokens_burned;
+        profile.num_games = self.num_games;
+        profile.num_split_bonuses = self.num_split_bonuses;
+        profile.average_num_splits = self.average_num_splits;
+        profile.average_stake_amount = self.average_stake_amount;
+        profile.season_stats = self.season_stats;
+        profile.burn_rate_check_failures = self.burn_rate_check_failures;
+        profile.flagged_slot = self.flagged_slot;
+        profile.locked_until_slot = self.locked_until_slot;
+        profile.luck_expected_gained = self.luck_expected_gained;
+        profile.luck_actual_gained = self.luck_actual_gained;
+        profile.luck_variance = self.luck_variance;
+        profile.luck_num_games = self.luck_num_games;
+        profile.luck_sprt = self.luck_sprt;
+        profile.stats = self.stats;
+        profile.achievements = self.achievements;
+        profile.claimed_achievements = self.claimed_achievements;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recreated_profile_inherits_stats() {
+        let profile = PlayerProfile {
+            is_initialized: true,
+            bump: 254,
+            last_played_slot: 1_000,
+            tokens_gained: 500_000_000_000,
+            tokens_burned: 20_000_000_000,
+            num_games: 300,
+            num_split_bonuses: 2,
+            average_num_splits: 600_000_000_000,
+            average_stake_amount: 40_000_000_000,
+            burn_rate_check_failures: 3,
+            flagged_slot: 900,
+            locked_until_slot: 2_100,
+            luck_variance: 12_345,
+            luck_num_games: 150,
+            num_referrals: 7,
+            ..Default::default()
+        };
+        let mut tombstone = ProfileTombstone::default();
+        tombstone.record(&profile);
+        assert_eq!(tombstone.num_closes, 1);
+
+        let mut recreated = PlayerProfile::default();
+        recreated.initialize(254);
+        tombstone.restore(&mut recreated);
+
+        assert_eq!(recreated.num_games, profile.num_games);
+        assert_eq!(recreated.num_split_bonuses, profile.num_split_bonuses);
+        assert_eq!(recreated.tokens_burned, profile.tokens_burned);
+        assert_eq!(recreated.burn_rate_check_failures, 3);
+        assert_eq!(recreated.luck_num_games, 150);
+        assert!(recreated.is_locked_out(2_000));
+        assert_eq!(
+            recreated.can_get_split_bonus(DEFAULT_SPLIT_BONUS_CONFIDENCE_BPS),
+            profile.can_get_split_bonus(DEFAULT_SPLIT_BONUS_CONFIDENCE_BPS)
+        );
+        assert_eq!(
+            recreated.passes_burn_rate_check(),
+            profile.passes_burn_rate_check()
+        );
+        // Referrals stay with the closed profile
+        assert_eq!(recreated.num_referrals, 0);
+    }
+}
@@ -0,0 +1,36 @@
+use super::*;
+
+// Snapshot of the vault taken when a season ends, seeded by the season number
+#[account]
+pub struct SeasonArchive {
+    pub season: u32,
+    pub bump: u8,
+    pub start_slot: u64,
+    pub end_slot: u64,
+    // Games played during the season, the rest are running totals at the end of the season
+    pub num_games: u64,
+    pub num_splits: u16,
+    pub num_steals: u16,
+    pub burned_amount: u64,
+    pub mint_remaining: u64,
+    pub num_zero_stakes: u32,
+    pub num_hundred_stakes: u32,
+}
+
+impl SeasonArchive {
+    pub fn initialize(&mut self, game_vault: &GameVault, bump: u8, end_slot: u64) {
+        *self = SeasonArchive {
+            season: game_vault.season,
+            bump,
+            start_slot: game_vault.season_start_slot,
+            end_slot,
+            num_games: game_vault.season_num_games,
+            num_splits: game_vault.num_splits,
+            num_steals: game_vault.num_steals,
+            burned_amount: game_vault.burned_amount,
+            mint_remaining: game_vault.mint_remaining,
+            num_zero_stakes: game_vault.num_zero_stakes,
+            num_hundred_stakes: game_vault.num_hundred_stakes,
+        };
+    }
+}
@@ -0,0 +1,264 @@
+use super::*;
+
+pub const MAX_TEAM_NAME_LEN: usize = 32;
+pub const MAX_TEAM_MEMBERS: u16 = 50;
+// Roughly a day of slots, stops players hopping between teams during a competition
+pub const TEAM_CHANGE_COOLDOWN_SLOTS: u64 = 216_000;
+pub const TEAM_LEADERBOARD_SIZE: usize = 10;
+
+#[account]
+pub struct Team {
+    pub leader: Pubkey,
+    pub bump: u8,
+    // Zero padded utf8, also used as the seed so it's unique
+    pub name: [u8; MAX_TEAM_NAME_LEN],
+    pub max_members: u16,
+    pub num_members: u16,
+    pub num_games: u64,
+    pub tokens_gained: u64,
+    pub tokens_burned: u64,
+    pub num_splits: u64,
+    pub num_steals: u64,
+}
+
+impl Team {
+    pub fn initialize(
+        &mut self,
+        leader: Pubkey,
+        name: &str,
+        max_members: u16,
+        bump: u8,
+    ) -> Result<()> {
+        require!(
+            !name.is_empty() && name.len() <= MAX_TEAM_NAME_LEN,
+            SplitOrStealError::InvalidTeamName
+        );
+        require!(
+            max_members > 0 && max_members <= MAX_TEAM_MEMBERS,
+            SplitOrStealError::InvalidTeamSize
+        );
+
+        let mut padded_name = [0u8; MAX_TEAM_NAME_LEN];
+        padded_name[..name.len()].copy_from_slice(name.as_bytes());
+        *self = Team {
+            leader,
+            bump,
+            name: padded_name,
+            max_members,
+            num_members: 0,
+            num_games: 0,
+            tokens_gained: 0,
+            tokens_burned: 0,
+            num_splits: 0,
+            num_steals: 0,
+        };
+        Ok(())
+    }
+
+    pub fn add_member(&mut self) -> Result<()> {
+        require_gt!(
+            self.max_members,
+            self.num_members,
+            SplitOrStealError::TeamFull
+        );
+        self.num_members += 1;
+        Ok(())
+    }
+
+    pub fn remove_member(&mut self) {
+        self.num_members = self.num_members.saturating_sub(1);
+    }
+
+    pub fn record_game(&mut self, choice: Choice, tokens_gained: u64, tokens_burned: u64) {
+        self.num_games += 1;
+        self.tokens_gained += tokens_gained;
+        self.tokens_burned += tokens_burned;
+        match choice {
+            Choice::Split => self.num_splits += 1,
+            Choice::Steal => self.num_steals += 1,
+        }
+    }
+
+    // Net tokens won by the team, what the leaderboard ranks on
+    pub fn score(&self) -> i64 {
+        (self.tokens_gained as i128 - self.tokens_burned as i128)
+            .clamp(i64::MIN as i128, i64::MAX as i128) as i64
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct TeamLeaderboardEntry {
+    pub team: Pubkey,
+    pub score: i64,
+}
+
+#[account]
+pub struct TeamLeaderboard {
+    pub bump: u8,
+    pub num_entries: u8,
+    // Sorted highest score first, only the first num_entries are used
+    pub entries: [TeamLeaderboardEntry; TEAM_LEADERBOARD_SIZE],
+}
+
+impl TeamLeaderboard {
+    pub fn initialize(&mut self, bump: u8) {
+        self.bump = bump;
+        self.num_entries = 0;
+        self.entries = [TeamLeaderboardEntry::default(); TEAM_LEADERBOARD_SIZE];
+    }
+
+    pub fn update(&mut self, team: Pubkey, score: i64) {
+        let num_entries = self.num_entries as usize;
+        let existing = self.entries[..num_entries]
+            .iter()
+            .position(|entry| entry.team == team);
+
+        let index = match existing {
+            Some(index) => index,
+            None if num_entries < TEAM_LEADERBOARD_SIZE => {
+                self.num_entries += 1;
+                num_entries
+            }
+            None => {
+                // Full, so it has to beat the lowest score to get on
+                let last = TEAM_LEADERBOARD_SIZE - 1;
+                if score <= self.entries[last].score {
+                    return;
+                }
+                last
+            }
+        };
+        self.entries[index] = TeamLeaderboardEntry { team, score };
+
+        // Scores can move either way, bubble the entry into place
+        let mut index = index;
+        while index > 0 && self.entries[index].score > self.entries[index - 1].score {
+            self.entries.swap(index, index - 1);
+            index -= 1;
+        }
+        while index + 1 < self.num_entries as usize
+            && self.entries[index].score < self.entries[index + 1].score
+        {
+            self.entries.swap(index, index + 1);
+            index += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_team() -> Team {
+        let mut team = Team {
+            leader: Pubkey::default(),
+            bump: 0,
+            name: [0; MAX_TEAM_NAME_LEN],
+            max_members: 0,
+            num_members: 0,
+            num_games: 0,
+            tokens_gained: 0,
+            tokens_burned: 0,
+            num_splits: 0,
+            num_steals: 0,
+        };
+        team.initialize(Pubkey::default(), "gourds", 2, 255)
+            .unwrap();
+        team
+    }
+
+    #[test]
+    fn test_team_name_and_size_validation() {
+        let mut team = new_team();
+        assert_eq!(&team.name[..6], b"gourds");
+        assert!(team.name[6..].iter().all(|byte| *byte == 0));
+
+        assert!(team.initialize(Pubkey::default(), "", 2, 255).is_err());
+        assert!(team
+            .initialize(Pubkey::default(), &"a".repeat(33), 2, 255)
+            .is_err());
+        assert!(team
+            .initialize(Pubkey::default(), "gourds", MAX_TEAM_MEMBERS + 1, 255)
+            .is_err());
+    }
+
+    #[test]
+    fn test_team_member_cap() {
+        let mut team = new_team();
+        assert!(team.add_member().is_ok());
+        assert!(team.add_member().is_ok());
+        assert!(team.add_member().is_err());
+
+        team.remove_member();
+        assert!(team.add_member().is_ok());
+    }
+
+    #[test]
+    fn test_team_record_game() {
+        let mut team = new_team();
+        team.record_game(Choice::Split, 3_000_000_000, 0);
+        team.record_game(Choice::Steal, 0, 5_000_000_000);
+        assert_eq!(team.num_games, 2);
+        assert_eq!(team.num_splits, 1);
+        assert_eq!(team.num_steals, 1);
+        assert_eq!(team.score(), -2_000_000_000);
+    }
+
+    #[test]
+    fn test_leaderboard_ordering() {
+        let mut leaderboard = TeamLeaderboard {
+            bump: 0,
+            num_entries: 0,
+            entries: [TeamLeaderboardEntry::default(); TEAM_LEADERBOARD_SIZE],
+        };
+        let teams: Vec<Pubkey> = (0..TEAM_LEADERBOARD_SIZE + 1)
+            .map(|_| Pubkey::new_unique())
+            .collect();
+
+        for (i, team) in teams.iter().take(TEAM_LEADERBOARD_SIZE).enumerate() {
+            leaderboard.update(*team, i as i64 * 10);
+        }
+        assert_eq!(leaderboard.num_entries as usize, TEAM_LEADERBOARD_SIZE);
+        assert_eq!(
+            leaderboard.entries[0].team,
+            teams[TEAM_LEADERBOARD_SIZE - 1]
+        );
+        assert_eq!(
+            leaderboard.entries[TEAM_LEADERBOARD_SIZE - 1].team,
+            teams[0]
+        );
+
+        // Too low to get on a full board
+        let outsider = teams[TEAM_LEADERBOARD_SIZE];
+        leaderboard.update(outsider, -5);
+        assert!(leaderboard
+            .entries
+            .iter()
+            .all(|entry| entry.team != outsider));
+
+        // Beats the lowest, so it takes its place
+        leaderboard.update(outsider, 15);
+        assert_eq!(
+            leaderboard.entries[TEAM_LEADERBOARD_SIZE - 2].team,
+            outsider
+        );
+        assert!(leaderboard
+            .entries
+            .iter()
+            .all(|entry| entry.team != teams[0]));
+
+        // Existing entries move both up and down
+        leaderboard.update(teams[1], 1_000);
+        assert_eq!(leaderboard.entries[0].team, teams[1]);
+        leaderboard.update(teams[1], 0);
+        assert_eq!(
+            leaderboard.entries[TEAM_LEADERBOARD_SIZE - 1].team,
+            teams[1]
+        );
+        assert_eq!(leaderboard.num_entries as usize, TEAM_LEADERBOARD_SIZE);
+
+        for pair in leaderboard.entries.windows(2) {
+            assert!(pair[0].score >= pair[1].score);
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use super::*;
+
+// Holds the profile creation fees as lamports on the account itself, only the creator can withdraw them
+#[account]
+pub struct Treasury {
+    pub bump: u8,
+    pub total_fees_collected: u64,
+    pub num_fees: u64,
+    pub total_withdrawn: u64,
+}
+
+impl Treasury {
+    pub fn record_fee(&mut self, fee: u64) {
+        self.total_fees_collected += fee;
+        self.num_fees += 1;
+    }
+
+    // Everything above the rent exemption minimum can be withdrawn
+    pub fn withdrawable(lamports: u64, rent_exempt_minimum: u64) -> u64 {
+        lamports.saturating_sub(rent_exempt_minimum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_treasury_accounting() {
+        let mut treasury = Treasury {
+            bump: 0,
+            total_fees_collected: 0,
+            num_fees: 0,
+            total_withdrawn: 0,
+        };
+        treasury.record_fee(4_500_000);
+        treasury.record_fee(6_000_000);
+        assert_eq!(treasury.total_fees_collected, 10_500_000);
+        assert_eq!(treasury.num_fees, 2);
+
+        assert_eq!(Treasury::withdrawable(11_500_000, 1_000_000), 10_500_000);
+        assert_eq!(Treasury::withdrawable(500_000, 1_000_000), 0);
+    }
+}
@@ -0,0 +1,212 @@
+use super::*;
+
+pub const MAX_BASIS_POINTS: u16 = 10_000;
+// Split bonuses need at least a 1% chance of having got this many, same as the original table
+pub const DEFAULT_SPLIT_BONUS_CONFIDENCE_BPS: u16 = 9_900;
+// The creation fee is what makes sybil profiles cost something, a referrer sybilling their own referees
+// gets this share of it back so it has to stay small
+pub const MAX_REFERRAL_FEE_BPS: u16 = 2_500;
+
+pub fn apply_basis_points(amount: u64, bps: u16) -> u64 {
+    ((amount as u128 * bps as u128) / MAX_BASIS_POINTS as u128) as u64
+}
+
+// Admin tunable settings, everything defaults to off so a fresh vault plays exactly like the original game
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct VaultConfig {
+    // Share of a stolen stake sent to the jackpot instead of burned, in basis points
+    pub jackpot_bps: u16,
+    // One in this many plays wins the whole jackpot pool, zero turns the draw off
+    pub jackpot_odds: u32,
+    // Share of the profile creation fee paid to the referrer, in basis points, at most MAX_REFERRAL_FEE_BPS
+    pub referral_fee_bps: u16,
+    // The referrer's share of each payout, taken out of the player's winnings, in basis points
+    pub referral_payout_bps: u16,
+    // Vault wide plays allowed in one slot, zero means no cap
+    pub max_plays_per_slot: u32,
+    // Vault wide plays allowed per window of throttle_window_slots, zero means no cap
+    pub max_plays_per_window: u32,
+    pub throttle_window_slots: u64,
+    // A play dropping mint_remaining by more than this counts as a large drop, zero turns the cap off
+    pub large_drop_amount: u64,
+    pub max_large_drops_per_slot: u32,
+    // Odds multiplier the SPRT luck detector tests for, in basis points so 20_000 is twice the odds. Zero turns it off
+    pub sprt_odds_ratio_bps: u32,
+    // SPRT false positive and false negative rates, in basis points
+    pub sprt_alpha_bps: u16,
+    pub sprt_beta_bps: u16,
+    // Confidence the player's split bonuses aren't luck before they can get another, zero uses the default 99%
+    pub split_bonus_confidence_bps: u16,
+    // Pause the vault once vault wide payouts, burns or split bonuses are this many standard deviations out.
+    // 9 decimal places like the burn rate check's z score, zero turns it off
+    pub anomaly_pause_z_score: u64,
+    // Vault wide games before the anomaly check kicks in
+    pub anomaly_min_games: u64,
+    // Hold the profile fee on the profile as a refundable deposit instead of paying it to the treasury
+    pub profile_fee_as_deposit: bool,
+    // Games a player needs before claim_deposit, with clean luck checks the whole time
+    pub deposit_refund_min_games: u32,
+}
+
+impl VaultConfig {
+    pub fn validate(&self) -> Result<()> {
+        for bps in [self.jackpot_bps, self.referral_payout_bps] {
+            require_gte!(MAX_BASIS_POINTS, bps, SplitOrStealError::InvalidBasisPoints);
+        }
+        require_gte!(
+            MAX_REFERRAL_FEE_BPS,
+            self.referral_fee_bps,
+            SplitOrStealError::InvalidBasisPoints
+        );
+        require_gt!(
+            MAX_BASIS_POINTS,
+            self.split_bonus_confidence_bps,
+            SplitOrStealError::InvalidBasisPoints
+        );
+        // A cut of every loss with no draw would fill a pool nobody can ever win, a draw with no cut pays out nothing
+        require!(
+            (self.jackpot_bps == 0) == (self.jackpot_odds == 0),
+            SplitOrStealError::InvalidJackpotConfig
+        );
+        if self.sprt_enabled() {
+            require_gt!(
+                self.sprt_odds_ratio_bps,
+                MAX_BASIS_POINTS as u32,
+                SplitOrStealError::InvalidSprtConfig
+            );
+            require!(
+                self.sprt_alpha_bps > 0
+                    && self.sprt_beta_bps > 0
+                    && self.sprt_alpha_bps + self.sprt_beta_bps < MAX_BASIS_POINTS,
+                SplitOrStealError::InvalidSprtConfig
+            );
+        }
+        // Any fewer games and the luck checks a refund depends on can't have failed yet
+        if self.profile_fee_as_deposit {
+            require_gte!(
+                self.deposit_refund_min_games,
+                MIN_SIGNIFICANT_GAMES,
+                SplitOrStealError::InvalidDepositConfig
+            );
+        }
+        Ok(())
+    }
+
+    pub fn split_bonus_confidence_bps(&self) -> u16 {
+        if self.split_bonus_confidence_bps == 0 {
+            DEFAULT_SPLIT_BONUS_CONFIDENCE_BPS
+        } else {
+            self.split_bonus_confidence_bps
+        }
+    }
+
+    pub fn anomaly_pause_enabled(&self) -> bool {
+        self.anomaly_pause_z_score > 0
+    }
+
+    pub fn sprt_enabled(&self) -> bool {
+        self.sprt_odds_ratio_bps > 0
+    }
+
+    pub fn jackpot_enabled(&self) -> bool {
+        self.jackpot_bps > 0 || self.jackpot_odds > 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_basis_points() {
+        assert_eq!(apply_basis_points(6_000_000, 0), 0);
+        assert_eq!(apply_basis_points(6_000_000, 2_500), 1_500_000);
+        assert_eq!(apply_basis_points(6_000_000, MAX_BASIS_POINTS), 6_000_000);
+        // Rounds down so the referrer never gets more than the share
+        assert_eq!(apply_basis_points(3, 5_000), 1);
+        // No overflow on the largest amounts
+        assert_eq!(apply_basis_points(u64::MAX, MAX_BASIS_POINTS), u64::MAX);
+    }
+
+    #[test]
+    fn test_validate_config() {
+        assert!(VaultConfig::default().validate().is_ok());
+
+        let config = VaultConfig {
+            referral_fee_bps: MAX_REFERRAL_FEE_BPS,
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+
+        // Any more and sybil profiles made through a referrer's own wallet get too much of the fee back
+        let config = VaultConfig {
+            referral_fee_bps: MAX_REFERRAL_FEE_BPS + 1,
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+
+        let config = VaultConfig {
+            referral_payout_bps: MAX_BASIS_POINTS + 1,
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+
+        let config = VaultConfig {
+            jackpot_bps: 500,
+            jackpot_odds: 10_000,
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+
+        // The jackpot needs both its cut and its draw
+        let config = VaultConfig {
+            jackpot_bps: 500,
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+
+        let config = VaultConfig {
+            jackpot_odds: 10_000,
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+
+        let config = VaultConfig {
+            sprt_odds_ratio_bps: 20_000,
+            sprt_alpha_bps: 100,
+            sprt_beta_bps: 1_000,
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+
+        // Has to test for better than fair odds
+        let config = VaultConfig {
+            sprt_odds_ratio_bps: MAX_BASIS_POINTS as u32,
+            sprt_alpha_bps: 100,
+            sprt_beta_bps: 1_000,
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+
+        let config = VaultConfig {
+            sprt_odds_ratio_bps: 20_000,
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+
+        // A deposit needs enough games for the luck checks to mean something
+        let config = VaultConfig {
+            profile_fee_as_deposit: true,
+            deposit_refund_min_games: MIN_SIGNIFICANT_GAMES - 1,
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+
+        let config = VaultConfig {
+            profile_fee_as_deposit: true,
+            deposit_refund_min_games: MIN_SIGNIFICANT_GAMES,
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+    }
+}
@@ -104,11 +104,27 @@ async function playSplitOrStealGame(provider, splitOrSteal, options) {
           .playGame(choice, stakeAmount)
           .accounts({
             player: options.payer.publicKey,
+            payer: options.payer.publicKey,
             playerProfile: options.playerProfilePDA,
+            profileTombstone: PublicKey.findProgramAddressSync(
+              [
+                Buffer.from("tombstone"),
+                options.gameVaultPDA.toBuffer(),
+                options.payer.publicKey.toBuffer(),
+              ],
+              splitOrSteal.programId
+            )[0],
+            legacyProfile: PublicKey.findProgramAddressSync(
+              [options.payer.publicKey.toBuffer()],
+              splitOrSteal.programId
+            )[0],
             playerTokenAccount: options.playerTokenAccountPDA,
             gameVault: options.gameVaultPDA,
             gameVaultTokenAccount: options.gameVaultTokenAccountPDA,
-            creator: options.payer.publicKey,
+            treasury: PublicKey.findProgramAddressSync(
+              [Buffer.from("treasury"), options.gameVaultPDA.toBuffer()],
+              splitOrSteal.programId
+            )[0],
             mint: options.TOKEN_MINT,
             slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
             instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
@@ -255,7 +271,11 @@ async function main() {
   );
 
   const [playerProfilePDA] = PublicKey.findProgramAddressSync(
-    [payer.publicKey.toBuffer()],
+    [
+      Buffer.from("profile"),
+      gameVaultPDA.toBuffer(),
+      payer.publicKey.toBuffer(),
+    ],
     splitOrSteal.programId
   );
 
@@ -37,6 +37,7 @@ import { createMint, createAccount, getMint, mintTo } from "spl-token-bankrun";
 import { associated } from "@coral-xyz/anchor/dist/cjs/utils/pubkey";
 
 const NUM_SLOTS_COOLDOWN = 3;
+const BURN_RATE_LOCKOUT_SLOTS = 150;
 
 async function advanceClockBySlots(
   context: ProgramTestContext,
@@ -55,6 +56,36 @@ async function advanceClockBySlots(
   );
 }
 
+function findPlayerProfilePDA(player: PublicKey) {
+  const gameVault = PublicKey.findProgramAddressSync(
+    [Buffer.from("lord of the gourd")],
+    SPLIT_OR_STEAL_PROGRAM_ID
+  )[0];
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("profile"), gameVault.toBuffer(), player.toBuffer()],
+    SPLIT_OR_STEAL_PROGRAM_ID
+  )[0];
+}
+
+// Profiles were seeded by the player alone before they were namespaced by vault
+function findLegacyProfilePDA(player: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [player.toBuffer()],
+    SPLIT_OR_STEAL_PROGRAM_ID
+  )[0];
+}
+
+function findProfileTombstonePDA(player: PublicKey) {
+  const gameVault = PublicKey.findProgramAddressSync(
+    [Buffer.from("lord of the gourd")],
+    SPLIT_OR_STEAL_PROGRAM_ID
+  )[0];
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("tombstone"), gameVault.toBuffer(), player.toBuffer()],
+    SPLIT_OR_STEAL_PROGRAM_ID
+  )[0];
+}
+
 async function createNewMint(
   provider,
   decimals,
@@ -132,6 +163,7 @@ describe("split_or_steal", async function () {
     tokenAmount,
     gameVaultPDA,
     gameVaultTokenAccountPDA,
+    treasuryPDA,
     playerTokenAccountPDA,
     playerProfilePDA,
     TOKEN_MINT;
@@ -159,6 +191,112 @@ describe("split_or_steal", async function () {
     // );
   });
 
+  // Players nobody else has touched, funded so they pay for their own profile
+  async function createFundedPlayer() {
+    const player = anchor.web3.Keypair.generate();
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        anchor.web3.SystemProgram.transfer({
+          fromPubkey: payer.publicKey,
+          toPubkey: player.publicKey,
+          lamports: 1_000_000_000,
+        })
+      ),
+      [payer]
+    );
+    return player;
+  }
+
+  // Unlike setting the clock this moves the blockhash on too, so the same play can be sent again
+  async function warpBySlots(numSlots: number) {
+    const currentClock = await banksClient.getClock();
+    context.warpToSlot(currentClock.slot + BigInt(numSlots));
+  }
+
+  function playAccounts(player: PublicKey) {
+    return {
+      player,
+      payer: player,
+      playerProfile: findPlayerProfilePDA(player),
+      profileTombstone: findProfileTombstonePDA(player),
+      legacyProfile: findLegacyProfilePDA(player),
+      playerTokenAccount: token.getAssociatedTokenAddressSync(
+        TOKEN_MINT,
+        player
+      ),
+      gameVault: gameVaultPDA,
+      gameVaultTokenAccount: gameVaultTokenAccountPDA,
+      treasury: treasuryPDA,
+      mint: TOKEN_MINT,
+      slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
+      instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+      systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: token.ASSOCIATED_TOKEN_PROGRAM_ID,
+    };
+  }
+
+  // Tests changing the config put back what they found, the rest of the suite plays on the default config
+  async function updateConfig(config) {
+    await splitOrSteal.methods
+      .updateConfig(config)
+      .accounts({ creator: payer.publicKey, gameVault: gameVaultPDA })
+      .rpc();
+  }
+
+  // Luck check state that would take thousands of games to reach is written straight into the account
+  async function patchAccount(
+    address: PublicKey,
+    accountName: string,
+    fields: object
+  ) {
+    const account = await banksClient.getAccount(address);
+    const decoded = splitOrSteal.coder.accounts.decode(
+      accountName,
+      Buffer.from(account.data)
+    );
+    const data = await splitOrSteal.coder.accounts.encode(accountName, {
+      ...decoded,
+      ...fields,
+    });
+    context.setAccount(address, {
+      ...account,
+      data: Buffer.concat([
+        data,
+        Buffer.alloc(account.data.length - data.length),
+      ]),
+    });
+  }
+
+  // Simulated, bankrun only hands back the logs the events are in that way. Send it with playGame after
+  async function simulatePlay(player: Keypair, extraAccounts = {}) {
+    const transaction = await splitOrSteal.methods
+      .playGame({ split: {} }, 0)
+      .accounts({ ...playAccounts(player.publicKey), ...extraAccounts })
+      .transaction();
+    transaction.recentBlockhash = (await banksClient.getLatestBlockhash())[0];
+    transaction.feePayer = player.publicKey;
+    transaction.sign(player);
+    const simulationResult = await banksClient.simulateTransaction(transaction);
+    const logMessages = simulationResult.meta.logMessages;
+    const eventParser = new anchor.EventParser(
+      SPLIT_OR_STEAL_PROGRAM_ID,
+      splitOrSteal.coder
+    );
+    return {
+      logMessages,
+      events: [...eventParser.parseLogs(logMessages)],
+    };
+  }
+
+  async function playGame(player: Keypair, extraAccounts = {}) {
+    await splitOrSteal.methods
+      .playGame({ split: {} }, 0)
+      .accounts({ ...playAccounts(player.publicKey), ...extraAccounts })
+      .signers([player])
+      .rpc();
+  }
+
   describe("#initialize_vault", async function () {
     it("initializes the game vault", async function () {
       // Create a new mint using the helper function
@@ -258,6 +396,21 @@ describe("split_or_steal", async function () {
         payer.publicKey.toBase58()
       );
 
+      // Profile creation fees are paid into the treasury, which has to exist before anyone plays
+      treasuryPDA = PublicKey.findProgramAddressSync(
+        [Buffer.from("treasury"), gameVaultPDA.toBuffer()],
+        SPLIT_OR_STEAL_PROGRAM_ID
+      )[0];
+      await splitOrSteal.methods
+        .initializeTreasury()
+        .accounts({
+          creator: payer.publicKey,
+          gameVault: gameVaultPDA,
+          treasury: treasuryPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
       let mintAccount = await getMint(banksClient, TOKEN_MINT);
       assert(mintAccount.mintAuthority === null);
       assert(mintAccount.freezeAuthority === null);
@@ -276,10 +429,7 @@ describe("split_or_steal", async function () {
         payer.publicKey
       );
 
-      playerProfilePDA = PublicKey.findProgramAddressSync(
-        [payer.publicKey.toBuffer()],
-        SPLIT_OR_STEAL_PROGRAM_ID
-      )[0];
+      playerProfilePDA = findPlayerProfilePDA(payer.publicKey);
       // Advance the clock to simulate the passing of time, if needed
       //   await advanceClockBySlots(context, 100);
       //   console.log("sysvar slot hashes", anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY);
@@ -305,11 +455,14 @@ describe("split_or_steal", async function () {
         .playGame(choice, 0)
         .accounts({
           player: payer.publicKey,
+          payer: payer.publicKey,
           playerProfile: playerProfilePDA,
+          profileTombstone: findProfileTombstonePDA(payer.publicKey),
+          legacyProfile: findLegacyProfilePDA(payer.publicKey),
           playerTokenAccount: playerTokenAccountPDA,
           gameVault: gameVaultPDA,
           gameVaultTokenAccount: gameVaultTokenAccountPDA,
-          creator: payer.publicKey,
+          treasury: treasuryPDA,
           mint: TOKEN_MINT,
           slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
           instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
@@ -388,10 +541,7 @@ describe("split_or_steal", async function () {
         newPlayer.publicKey
       );
 
-      const playerProfilePDA = PublicKey.findProgramAddressSync(
-        [newPlayer.publicKey.toBuffer()],
-        SPLIT_OR_STEAL_PROGRAM_ID
-      )[0];
+      const playerProfilePDA = findPlayerProfilePDA(newPlayer.publicKey);
 
       // Fetch the new player's balance before the transaction
       const balanceBefore = await banksClient.getBalance(newPlayer.publicKey);
@@ -401,11 +551,14 @@ describe("split_or_steal", async function () {
         .playGame(choice, 0)
         .accounts({
           player: newPlayer.publicKey,
+          payer: newPlayer.publicKey,
           playerProfile: playerProfilePDA,
+          profileTombstone: findProfileTombstonePDA(newPlayer.publicKey),
+          legacyProfile: findLegacyProfilePDA(newPlayer.publicKey),
           playerTokenAccount: playerTokenAccountPDA,
           gameVault: gameVaultPDA,
           gameVaultTokenAccount: gameVaultTokenAccountPDA,
-          creator: payer.publicKey,
+          treasury: treasuryPDA,
           mint: TOKEN_MINT,
           slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
           instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
@@ -445,6 +598,59 @@ describe("split_or_steal", async function () {
       // assert(gameVaultAccount.mintRemaining.lt(tokenAmount.sub(stakeAmount)));
     });
 
+    it("lets a relayer pay for a new player with no SOL", async function () {
+      // Never funded, the relayer covers rent, the profile fee and the transaction fee
+      const newPlayer = anchor.web3.Keypair.generate();
+
+      const playerTokenAccountPDA = token.getAssociatedTokenAddressSync(
+        TOKEN_MINT,
+        newPlayer.publicKey
+      );
+
+      const playerProfilePDA = findPlayerProfilePDA(newPlayer.publicKey);
+
+      const playGameInstruction = await splitOrSteal.methods
+        .playGame({ split: {} }, 0)
+        .accounts({
+          player: newPlayer.publicKey,
+          payer: payer.publicKey,
+          playerProfile: playerProfilePDA,
+          profileTombstone: findProfileTombstonePDA(newPlayer.publicKey),
+          legacyProfile: findLegacyProfilePDA(newPlayer.publicKey),
+          playerTokenAccount: playerTokenAccountPDA,
+          gameVault: gameVaultPDA,
+          gameVaultTokenAccount: gameVaultTokenAccountPDA,
+          treasury: treasuryPDA,
+          mint: TOKEN_MINT,
+          slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
+          instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: token.ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .instruction();
+
+      const transaction = new anchor.web3.Transaction().add(
+        anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({
+          units: 130_000,
+        }),
+        playGameInstruction
+      );
+      transaction.recentBlockhash = (await banksClient.getLatestBlockhash())[0];
+      transaction.feePayer = payer.publicKey;
+      transaction.sign(payer, newPlayer);
+
+      // Only simulated so the extra player doesn't change the vault totals checked at the end
+      const simulationResult = await context.banksClient.simulateTransaction(
+        transaction
+      );
+      assert.isNull(
+        simulationResult.result,
+        "Relayed play should succeed without the player holding SOL"
+      );
+      assert.equal(Number(await banksClient.getBalance(newPlayer.publicKey)), 0);
+    });
+
     it("should log game results and compute units usage for a new player", async function () {
       const choice = { split: {} }; // Assuming choice is an enum-like object
 
@@ -471,20 +677,20 @@ describe("split_or_steal", async function () {
           newPlayer.publicKey
         );
 
-        const playerProfilePDA = PublicKey.findProgramAddressSync(
-          [newPlayer.publicKey.toBuffer()],
-          SPLIT_OR_STEAL_PROGRAM_ID
-        )[0];
+        const playerProfilePDA = findPlayerProfilePDA(newPlayer.publicKey);
 
         const playGameInstruction = await splitOrSteal.methods
           .playGame(choice, 0)
           .accounts({
             player: newPlayer.publicKey,
+            payer: newPlayer.publicKey,
             playerProfile: playerProfilePDA,
+            profileTombstone: findProfileTombstonePDA(newPlayer.publicKey),
+            legacyProfile: findLegacyProfilePDA(newPlayer.publicKey),
             playerTokenAccount: playerTokenAccountPDA,
             gameVault: gameVaultPDA,
             gameVaultTokenAccount: gameVaultTokenAccountPDA,
-            creator: payer.publicKey,
+            treasury: treasuryPDA,
             mint: TOKEN_MINT,
             slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
             instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
@@ -582,11 +788,14 @@ describe("split_or_steal", async function () {
           .playGame(choice, invalidStakeAmount)
           .accounts({
             player: payer.publicKey,
+            payer: payer.publicKey,
             playerProfile: playerProfilePDA,
+            profileTombstone: findProfileTombstonePDA(payer.publicKey),
+            legacyProfile: findLegacyProfilePDA(payer.publicKey),
             playerTokenAccount: playerTokenAccountPDA,
             gameVault: gameVaultPDA,
             gameVaultTokenAccount: gameVaultTokenAccountPDA,
-            creator: payer.publicKey,
+            treasury: treasuryPDA,
             mint: TOKEN_MINT,
             slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
             instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
@@ -625,11 +834,14 @@ describe("split_or_steal", async function () {
           .playGame(choice, invalidStakeAmount)
           .accounts({
             player: payer.publicKey,
+            payer: payer.publicKey,
             playerProfile: playerProfilePDA,
+            profileTombstone: findProfileTombstonePDA(payer.publicKey),
+            legacyProfile: findLegacyProfilePDA(payer.publicKey),
             playerTokenAccount: playerTokenAccountPDA,
             gameVault: gameVaultPDA,
             gameVaultTokenAccount: gameVaultTokenAccountPDA,
-            creator: payer.publicKey,
+            treasury: treasuryPDA,
             mint: TOKEN_MINT,
             slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
             instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
@@ -686,11 +898,14 @@ describe("split_or_steal", async function () {
           .playGame(choice, stakeAmount)
           .accounts({
             player: payer.publicKey,
+            payer: payer.publicKey,
             playerProfile: playerProfilePDA,
+            profileTombstone: findProfileTombstonePDA(payer.publicKey),
+            legacyProfile: findLegacyProfilePDA(payer.publicKey),
             playerTokenAccount: playerTokenAccountPDA,
             gameVault: gameVaultPDA,
             gameVaultTokenAccount: gameVaultTokenAccountPDA,
-            creator: payer.publicKey,
+            treasury: treasuryPDA,
             mint: TOKEN_MINT,
             slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
             instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
@@ -734,11 +949,14 @@ describe("split_or_steal", async function () {
           .playGame(choice, stakeAmount)
           .accounts({
             player: payer.publicKey,
+            payer: payer.publicKey,
             playerProfile: playerProfilePDA,
+            profileTombstone: findProfileTombstonePDA(payer.publicKey),
+            legacyProfile: findLegacyProfilePDA(payer.publicKey),
             playerTokenAccount: playerTokenAccountPDA,
             gameVault: gameVaultPDA,
             gameVaultTokenAccount: gameVaultTokenAccountPDA,
-            creator: payer.publicKey,
+            treasury: treasuryPDA,
             mint: TOKEN_MINT,
             slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
             instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
@@ -806,10 +1024,7 @@ describe("split_or_steal", async function () {
         "Player should have insufficient SOL for the test"
       );
       // Calculate the PDAs for the new player
-      const [newPlayerProfilePDA, _] = PublicKey.findProgramAddressSync(
-        [newPlayer.publicKey.toBuffer()],
-        SPLIT_OR_STEAL_PROGRAM_ID
-      );
+      const newPlayerProfilePDA = findPlayerProfilePDA(newPlayer.publicKey);
 
       const newPlayerTokenAccountPDA = token.getAssociatedTokenAddressSync(
         TOKEN_MINT,
@@ -821,11 +1036,14 @@ describe("split_or_steal", async function () {
           .playGame(choice, stakeAmount)
           .accounts({
             player: newPlayer.publicKey,
+            payer: newPlayer.publicKey,
             playerProfile: newPlayerProfilePDA,
+            profileTombstone: findProfileTombstonePDA(newPlayer.publicKey),
+            legacyProfile: findLegacyProfilePDA(newPlayer.publicKey),
             playerTokenAccount: newPlayerTokenAccountPDA,
             gameVault: gameVaultPDA,
             gameVaultTokenAccount: gameVaultTokenAccountPDA,
-            creator: payer.publicKey,
+            treasury: treasuryPDA,
             mint: TOKEN_MINT,
             slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
             instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
@@ -871,11 +1089,14 @@ describe("split_or_steal", async function () {
         .playGame(choice, stakeAmount)
         .accounts({
           player: payer.publicKey,
+          payer: payer.publicKey,
           playerProfile: playerProfilePDA,
+          profileTombstone: findProfileTombstonePDA(payer.publicKey),
+          legacyProfile: findLegacyProfilePDA(payer.publicKey),
           playerTokenAccount: playerTokenAccountPDA,
           gameVault: gameVaultPDA,
           gameVaultTokenAccount: gameVaultTokenAccountPDA,
-          creator: payer.publicKey,
+          treasury: treasuryPDA,
           mint: TOKEN_MINT,
           slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
           instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
@@ -891,11 +1112,14 @@ describe("split_or_steal", async function () {
           .playGame(choice, stakeAmount)
           .accounts({
             player: payer.publicKey,
+            payer: payer.publicKey,
             playerProfile: playerProfilePDA,
+            profileTombstone: findProfileTombstonePDA(payer.publicKey),
+            legacyProfile: findLegacyProfilePDA(payer.publicKey),
             playerTokenAccount: playerTokenAccountPDA,
             gameVault: gameVaultPDA,
             gameVaultTokenAccount: gameVaultTokenAccountPDA,
-            creator: payer.publicKey,
+            treasury: treasuryPDA,
             mint: TOKEN_MINT,
             slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
             instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
@@ -917,6 +1141,40 @@ describe("split_or_steal", async function () {
       }
     });
 
+    it("should fail if play_game_cpi is called directly", async function () {
+      try {
+        await splitOrSteal.methods
+          .playGameCpi({ split: {} }, 10)
+          .accounts({
+            player: payer.publicKey,
+            payer: payer.publicKey,
+            playerProfile: playerProfilePDA,
+            profileTombstone: findProfileTombstonePDA(payer.publicKey),
+            legacyProfile: findLegacyProfilePDA(payer.publicKey),
+            playerTokenAccount: playerTokenAccountPDA,
+            gameVault: gameVaultPDA,
+            gameVaultTokenAccount: gameVaultTokenAccountPDA,
+            treasury: treasuryPDA,
+            mint: TOKEN_MINT,
+            slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
+            instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: token.ASSOCIATED_TOKEN_PROGRAM_ID,
+          })
+          .rpc();
+        assert.fail(
+          "The transaction should have failed as it wasn't called through CPI"
+        );
+      } catch (err) {
+        assert.include(
+          err.message,
+          "NotCalledViaCpi",
+          "Calling play_game_cpi at the top level should cause an error"
+        );
+      }
+    });
+
     it("should fail if previous instructions are not from the Compute Budget program", async function () {
       const stakeAmount = new BN(10); // 10 tokens
       const choice = { split: {} }; // Assuming choice is an enum-like object
@@ -926,11 +1184,14 @@ describe("split_or_steal", async function () {
           .playGame(choice, stakeAmount)
           .accounts({
             player: payer.publicKey,
+            payer: payer.publicKey,
             playerProfile: playerProfilePDA,
+            profileTombstone: findProfileTombstonePDA(payer.publicKey),
+            legacyProfile: findLegacyProfilePDA(payer.publicKey),
             playerTokenAccount: playerTokenAccountPDA,
             gameVault: gameVaultPDA,
             gameVaultTokenAccount: gameVaultTokenAccountPDA,
-            creator: payer.publicKey,
+            treasury: treasuryPDA,
             mint: TOKEN_MINT,
             slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
             instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
@@ -968,11 +1229,14 @@ describe("split_or_steal", async function () {
           .playGame(choice, stakeAmount)
           .accounts({
             player: payer.publicKey,
+            payer: payer.publicKey,
             playerProfile: playerProfilePDA,
+            profileTombstone: findProfileTombstonePDA(payer.publicKey),
+            legacyProfile: findLegacyProfilePDA(payer.publicKey),
             playerTokenAccount: playerTokenAccountPDA,
             gameVault: gameVaultPDA,
             gameVaultTokenAccount: gameVaultTokenAccountPDA,
-            creator: payer.publicKey,
+            treasury: treasuryPDA,
             mint: TOKEN_MINT,
             slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
             instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
@@ -1002,68 +1266,194 @@ describe("split_or_steal", async function () {
     });
   });
 
-  describe("#play_game_slot_hash_deserialization", async function () {
-    it("should fail if slot hashes account cannot be deserialized correctly", async function () {
-      const stakeAmount = new BN(10); // 10 tokens
-      const choice = { split: {} }; // Assuming choice is an enum-like object
+  describe("#instruction_allowlist", async function () {
+    let instructionAllowlistPDA;
 
-      // Use an incorrect account for slot_hashes to simulate deserialization failure
-      const invalidSlotHashes = PublicKey.default;
+    before(async function () {
+      instructionAllowlistPDA = PublicKey.findProgramAddressSync(
+        [Buffer.from("allowlist"), gameVaultPDA.toBuffer()],
+        SPLIT_OR_STEAL_PROGRAM_ID
+      )[0];
+
+      await splitOrSteal.methods
+        .setInstructionAllowlist([
+          { programId: MEMO_PROGRAM_ID, position: { after: {} } },
+        ])
+        .accounts({
+          creator: payer.publicKey,
+          gameVault: gameVaultPDA,
+          instructionAllowlist: instructionAllowlistPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    });
+
+    it("allows an allowlisted memo after the play_game instruction", async function () {
+      advanceClockBySlots(context, NUM_SLOTS_COOLDOWN);
+      const choice = { split: {} };
+
+      const memoInstruction = new anchor.web3.TransactionInstruction({
+        keys: [],
+        programId: MEMO_PROGRAM_ID,
+        data: Buffer.from("split or steal"),
+      });
+
+      await splitOrSteal.methods
+        .playGame(choice, 0)
+        .accounts({
+          player: payer.publicKey,
+          payer: payer.publicKey,
+          playerProfile: playerProfilePDA,
+          profileTombstone: findProfileTombstonePDA(payer.publicKey),
+          legacyProfile: findLegacyProfilePDA(payer.publicKey),
+          playerTokenAccount: playerTokenAccountPDA,
+          gameVault: gameVaultPDA,
+          gameVaultTokenAccount: gameVaultTokenAccountPDA,
+          treasury: treasuryPDA,
+          mint: TOKEN_MINT,
+          slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
+          instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: token.ASSOCIATED_TOKEN_PROGRAM_ID,
+          instructionAllowlist: instructionAllowlistPDA,
+        })
+        .postInstructions([memoInstruction])
+        .rpc();
+    });
+
+    it("should fail if an allowlisted program is in the wrong position", async function () {
+      advanceClockBySlots(context, NUM_SLOTS_COOLDOWN);
+      const choice = { split: {} };
+
+      const memoInstruction = new anchor.web3.TransactionInstruction({
+        keys: [],
+        programId: MEMO_PROGRAM_ID,
+        data: Buffer.from("split or steal"),
+      });
 
       try {
         await splitOrSteal.methods
-          .playGame(choice, stakeAmount)
+          .playGame(choice, 0)
           .accounts({
             player: payer.publicKey,
+            payer: payer.publicKey,
             playerProfile: playerProfilePDA,
+            profileTombstone: findProfileTombstonePDA(payer.publicKey),
+            legacyProfile: findLegacyProfilePDA(payer.publicKey),
             playerTokenAccount: playerTokenAccountPDA,
             gameVault: gameVaultPDA,
             gameVaultTokenAccount: gameVaultTokenAccountPDA,
-            creator: payer.publicKey,
+            treasury: treasuryPDA,
             mint: TOKEN_MINT,
-            slotHashes: invalidSlotHashes,
+            slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
             instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
             systemProgram: SystemProgram.programId,
             tokenProgram: TOKEN_PROGRAM_ID,
             associatedTokenProgram: token.ASSOCIATED_TOKEN_PROGRAM_ID,
+            instructionAllowlist: instructionAllowlistPDA,
           })
-          .preInstructions([
-            anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({
-              units: 100_000,
-            }),
-            anchor.web3.ComputeBudgetProgram.setComputeUnitPrice({
-              microLamports: 100,
+          .preInstructions([memoInstruction])
+          .rpc();
+        assert.fail(
+          "The transaction should have failed due to the memo being before play_game"
+        );
+      } catch (err) {
+        assert.include(
+          err.message,
+          "InstructionNotAllowedBefore",
+          "Memo before play_game should cause an error"
+        );
+      }
+    });
+
+    it("should fail if a program not in the allowlist comes after play_game", async function () {
+      advanceClockBySlots(context, NUM_SLOTS_COOLDOWN);
+      const choice = { split: {} };
+
+      try {
+        await splitOrSteal.methods
+          .playGame(choice, 0)
+          .accounts({
+            player: payer.publicKey,
+            payer: payer.publicKey,
+            playerProfile: playerProfilePDA,
+            profileTombstone: findProfileTombstonePDA(payer.publicKey),
+            legacyProfile: findLegacyProfilePDA(payer.publicKey),
+            playerTokenAccount: playerTokenAccountPDA,
+            gameVault: gameVaultPDA,
+            gameVaultTokenAccount: gameVaultTokenAccountPDA,
+            treasury: treasuryPDA,
+            mint: TOKEN_MINT,
+            slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
+            instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: token.ASSOCIATED_TOKEN_PROGRAM_ID,
+            instructionAllowlist: instructionAllowlistPDA,
+          })
+          .postInstructions([
+            anchor.web3.SystemProgram.transfer({
+              fromPubkey: payer.publicKey,
+              toPubkey: gameVaultPDA,
+              lamports: 1,
             }),
           ])
           .rpc();
         assert.fail(
-          "The transaction should have failed due to slot hashes deserialization error"
+          "The transaction should have failed due to a program not in the allowlist"
         );
       } catch (err) {
         assert.include(
           err.message,
-          "ConstraintAddress.",
-          "Wrong address for slot hashes"
+          "InstructionNotAllowedAfter",
+          "Program not in the allowlist should cause an error"
+        );
+      }
+    });
+
+    it("should fail to allow a program that isn't passive after play_game", async function () {
+      try {
+        await splitOrSteal.methods
+          .setInstructionAllowlist([
+            { programId: SystemProgram.programId, position: { either: {} } },
+          ])
+          .accounts({
+            creator: payer.publicKey,
+            gameVault: gameVaultPDA,
+            instructionAllowlist: instructionAllowlistPDA,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        assert.fail(
+          "The transaction should have failed due to the program not being passive"
+        );
+      } catch (err) {
+        assert.include(
+          err.message,
+          "ProgramNotPassive",
+          "Allowing a program that isn't passive after play_game should cause an error"
         );
       }
     });
   });
-  describe("#play_game_logging", async function () {
-    it("should log game results correctly", async function () {
-      advanceClockBySlots(context, NUM_SLOTS_COOLDOWN);
-      const stakeAmount = new BN(1); // 10 tokens
-      const choice = { split: {} }; // Assuming choice is an enum-like object
 
-      // Get the playGame instruction
-      const playGameInstruction = await splitOrSteal.methods
-        .playGame(choice, stakeAmount)
+  describe("#play_game_durable_nonce", async function () {
+    let nonceKeypair: Keypair;
+
+    async function getPlayGameInstruction() {
+      return await splitOrSteal.methods
+        .playGame({ split: {} }, 0)
         .accounts({
           player: payer.publicKey,
+          payer: payer.publicKey,
           playerProfile: playerProfilePDA,
+          profileTombstone: findProfileTombstonePDA(payer.publicKey),
+          legacyProfile: findLegacyProfilePDA(payer.publicKey),
           playerTokenAccount: playerTokenAccountPDA,
           gameVault: gameVaultPDA,
           gameVaultTokenAccount: gameVaultTokenAccountPDA,
-          creator: payer.publicKey,
+          treasury: treasuryPDA,
           mint: TOKEN_MINT,
           slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
           instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
@@ -1071,26 +1461,235 @@ describe("split_or_steal", async function () {
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: token.ASSOCIATED_TOKEN_PROGRAM_ID,
         })
-        .signers([payer])
         .instruction();
+    }
+
+    async function getNonce() {
+      const nonceAccount = await banksClient.getAccount(nonceKeypair.publicKey);
+      return anchor.web3.NonceAccount.fromAccountData(
+        Buffer.from(nonceAccount.data)
+      ).nonce;
+    }
+
+    // Nonces can only be advanced once the blockhash has moved on
+    async function warpPastCooldown() {
+      const currentClock = await banksClient.getClock();
+      context.warpToSlot(currentClock.slot + BigInt(NUM_SLOTS_COOLDOWN + 1));
+    }
+
+    before(async function () {
+      nonceKeypair = Keypair.generate();
+      const createNonceTransaction = new anchor.web3.Transaction().add(
+        SystemProgram.createAccount({
+          fromPubkey: payer.publicKey,
+          newAccountPubkey: nonceKeypair.publicKey,
+          lamports: await provider.connection.getMinimumBalanceForRentExemption(
+            anchor.web3.NONCE_ACCOUNT_LENGTH
+          ),
+          space: anchor.web3.NONCE_ACCOUNT_LENGTH,
+          programId: SystemProgram.programId,
+        }),
+        SystemProgram.nonceInitialize({
+          noncePubkey: nonceKeypair.publicKey,
+          authorizedPubkey: payer.publicKey,
+        })
+      );
+      await provider.sendAndConfirm(createNonceTransaction, [nonceKeypair]);
+    });
 
-      // Construct a transaction
+    it("plays the game in a transaction signed against a durable nonce", async function () {
+      await warpPastCooldown();
+      const profileBefore = await splitOrSteal.account.playerProfile.fetch(
+        playerProfilePDA
+      );
+
+      // Sign against the nonce instead of a recent blockhash, like an offline signer would
       const transaction = new anchor.web3.Transaction().add(
-        playGameInstruction
+        SystemProgram.nonceAdvance({
+          noncePubkey: nonceKeypair.publicKey,
+          authorizedPubkey: payer.publicKey,
+        }),
+        await getPlayGameInstruction()
       );
-      transaction.recentBlockhash = (await banksClient.getLatestBlockhash())[0];
+      const nonceBefore = await getNonce();
+      transaction.recentBlockhash = nonceBefore;
       transaction.feePayer = payer.publicKey;
       transaction.sign(payer);
 
-      // Simulate the transaction
-      const simulationResult = await context.banksClient.simulateTransaction(
-        transaction
+      await banksClient.processTransaction(transaction);
+
+      const profileAfter = await splitOrSteal.account.playerProfile.fetch(
+        playerProfilePDA
+      );
+      assert.equal(profileAfter.numGames, profileBefore.numGames + 1);
+      assert.notEqual(
+        await getNonce(),
+        nonceBefore,
+        "Nonce should have been advanced"
       );
+    });
 
-      // Get log messages from the simulation metadata
-      const logMessages = simulationResult.meta.logMessages;
+    it("allows compute budget instructions after the nonce advance", async function () {
+      await warpPastCooldown();
 
-      // Define the expected log message
+      const transaction = new anchor.web3.Transaction().add(
+        SystemProgram.nonceAdvance({
+          noncePubkey: nonceKeypair.publicKey,
+          authorizedPubkey: payer.publicKey,
+        }),
+        anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({
+          units: 150_000,
+        }),
+        await getPlayGameInstruction()
+      );
+      transaction.recentBlockhash = await getNonce();
+      transaction.feePayer = payer.publicKey;
+      transaction.sign(payer);
+
+      await banksClient.processTransaction(transaction);
+    });
+
+    it("should fail if the nonce advance is not the first instruction", async function () {
+      await warpPastCooldown();
+
+      try {
+        await splitOrSteal.methods
+          .playGame({ split: {} }, 0)
+          .accounts({
+            player: payer.publicKey,
+            payer: payer.publicKey,
+            playerProfile: playerProfilePDA,
+            profileTombstone: findProfileTombstonePDA(payer.publicKey),
+            legacyProfile: findLegacyProfilePDA(payer.publicKey),
+            playerTokenAccount: playerTokenAccountPDA,
+            gameVault: gameVaultPDA,
+            gameVaultTokenAccount: gameVaultTokenAccountPDA,
+            treasury: treasuryPDA,
+            mint: TOKEN_MINT,
+            slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
+            instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: token.ASSOCIATED_TOKEN_PROGRAM_ID,
+          })
+          .preInstructions([
+            anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({
+              units: 150_000,
+            }),
+            SystemProgram.nonceAdvance({
+              noncePubkey: nonceKeypair.publicKey,
+              authorizedPubkey: payer.publicKey,
+            }),
+          ])
+          .rpc();
+        assert.fail(
+          "The transaction should have failed due to the nonce advance not being first"
+        );
+      } catch (err) {
+        assert.include(
+          err.message,
+          "InvalidComputeBudgetProgramId",
+          "Nonce advance after another instruction should cause an error"
+        );
+      }
+    });
+  });
+
+  describe("#play_game_slot_hash_deserialization", async function () {
+    it("should fail if slot hashes account cannot be deserialized correctly", async function () {
+      const stakeAmount = new BN(10); // 10 tokens
+      const choice = { split: {} }; // Assuming choice is an enum-like object
+
+      // Use an incorrect account for slot_hashes to simulate deserialization failure
+      const invalidSlotHashes = PublicKey.default;
+
+      try {
+        await splitOrSteal.methods
+          .playGame(choice, stakeAmount)
+          .accounts({
+            player: payer.publicKey,
+            payer: payer.publicKey,
+            playerProfile: playerProfilePDA,
+            profileTombstone: findProfileTombstonePDA(payer.publicKey),
+            legacyProfile: findLegacyProfilePDA(payer.publicKey),
+            playerTokenAccount: playerTokenAccountPDA,
+            gameVault: gameVaultPDA,
+            gameVaultTokenAccount: gameVaultTokenAccountPDA,
+            treasury: treasuryPDA,
+            mint: TOKEN_MINT,
+            slotHashes: invalidSlotHashes,
+            instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: token.ASSOCIATED_TOKEN_PROGRAM_ID,
+          })
+          .preInstructions([
+            anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({
+              units: 100_000,
+            }),
+            anchor.web3.ComputeBudgetProgram.setComputeUnitPrice({
+              microLamports: 100,
+            }),
+          ])
+          .rpc();
+        assert.fail(
+          "The transaction should have failed due to slot hashes deserialization error"
+        );
+      } catch (err) {
+        assert.include(
+          err.message,
+          "ConstraintAddress.",
+          "Wrong address for slot hashes"
+        );
+      }
+    });
+  });
+  describe("#play_game_logging", async function () {
+    it("should log game results correctly", async function () {
+      advanceClockBySlots(context, NUM_SLOTS_COOLDOWN);
+      const stakeAmount = new BN(1); // 10 tokens
+      const choice = { split: {} }; // Assuming choice is an enum-like object
+
+      // Get the playGame instruction
+      const playGameInstruction = await splitOrSteal.methods
+        .playGame(choice, stakeAmount)
+        .accounts({
+          player: payer.publicKey,
+          payer: payer.publicKey,
+          playerProfile: playerProfilePDA,
+          profileTombstone: findProfileTombstonePDA(payer.publicKey),
+          legacyProfile: findLegacyProfilePDA(payer.publicKey),
+          playerTokenAccount: playerTokenAccountPDA,
+          gameVault: gameVaultPDA,
+          gameVaultTokenAccount: gameVaultTokenAccountPDA,
+          treasury: treasuryPDA,
+          mint: TOKEN_MINT,
+          slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
+          instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: token.ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([payer])
+        .instruction();
+
+      // Construct a transaction
+      const transaction = new anchor.web3.Transaction().add(
+        playGameInstruction
+      );
+      transaction.recentBlockhash = (await banksClient.getLatestBlockhash())[0];
+      transaction.feePayer = payer.publicKey;
+      transaction.sign(payer);
+
+      // Simulate the transaction
+      const simulationResult = await context.banksClient.simulateTransaction(
+        transaction
+      );
+
+      // Get log messages from the simulation metadata
+      const logMessages = simulationResult.meta.logMessages;
+
+      // Define the expected log message
       const expectedLog = `Results: Stake Amount: ${(
         stakeAmount.toNumber() * 1_000_000_000
       ).toString()}, Player Choice: Split, Opponent Choice: `; // Adjust this according to the expected result format
@@ -1115,11 +1714,14 @@ describe("split_or_steal", async function () {
         .playGame(choice, stakeAmount)
         .accounts({
           player: payer.publicKey,
+          payer: payer.publicKey,
           playerProfile: playerProfilePDA,
+          profileTombstone: findProfileTombstonePDA(payer.publicKey),
+          legacyProfile: findLegacyProfilePDA(payer.publicKey),
           playerTokenAccount: playerTokenAccountPDA,
           gameVault: gameVaultPDA,
           gameVaultTokenAccount: gameVaultTokenAccountPDA,
-          creator: payer.publicKey,
+          treasury: treasuryPDA,
           mint: TOKEN_MINT,
           slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
           instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
@@ -1199,11 +1801,14 @@ describe("split_or_steal", async function () {
           .playGame(choice, stakeAmount)
           .accounts({
             player: payer.publicKey,
+            payer: payer.publicKey,
             playerProfile: playerProfilePDA,
+            profileTombstone: findProfileTombstonePDA(payer.publicKey),
+            legacyProfile: findLegacyProfilePDA(payer.publicKey),
             playerTokenAccount: playerTokenAccountPDA,
             gameVault: gameVaultPDA,
             gameVaultTokenAccount: gameVaultTokenAccountPDA,
-            creator: payer.publicKey,
+            treasury: treasuryPDA,
             mint: TOKEN_MINT,
             slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
             instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
@@ -1268,52 +1873,1457 @@ describe("split_or_steal", async function () {
     });
   });
 
-  describe("#play_game_run_out_clock", async function () {
-    it("should run transactions with random stake amounts and check final balances", async function () {
-      const maxAttempts = 5000; // Number of attempts to simulate
+  describe("#play_game_session", async function () {
+    let sessionPlayer: Keypair,
+      sessionKey: Keypair,
+      sessionPDA,
+      sessionPlayerTokenAccount,
+      sessionPlayerProfilePDA;
+
+    async function simulateSessionPlay(withSession: boolean) {
+      const accounts = {
+        player: sessionPlayer.publicKey,
+        payer: payer.publicKey,
+        playerProfile: sessionPlayerProfilePDA,
+        profileTombstone: findProfileTombstonePDA(sessionPlayer.publicKey),
+        legacyProfile: findLegacyProfilePDA(sessionPlayer.publicKey),
+        playerTokenAccount: sessionPlayerTokenAccount,
+        gameVault: gameVaultPDA,
+        gameVaultTokenAccount: gameVaultTokenAccountPDA,
+        treasury: treasuryPDA,
+        mint: TOKEN_MINT,
+        slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
+        instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: token.ASSOCIATED_TOKEN_PROGRAM_ID,
+      };
+      if (withSession) {
+        accounts["sessionKey"] = sessionKey.publicKey;
+        accounts["session"] = sessionPDA;
+      }
+      const playGameInstruction = await splitOrSteal.methods
+        .playGame({ split: {} }, 0)
+        .accounts(accounts)
+        .instruction();
 
-      // Get initial balances
-      const initialPlayerTokenAccount = await token.getAccount(
-        provider.connection,
-        playerTokenAccountPDA
+      const transaction = new anchor.web3.Transaction().add(
+        playGameInstruction
       );
-      const initialPlayerTokenAmount = Number(initialPlayerTokenAccount.amount);
-      const initialGameVault = await splitOrSteal.account.gameVault.fetch(
-        gameVaultPDA
+      transaction.recentBlockhash = (await banksClient.getLatestBlockhash())[0];
+      transaction.feePayer = payer.publicKey;
+      if (withSession) {
+        transaction.sign(payer, sessionKey);
+      } else {
+        transaction.sign(payer);
+      }
+
+      // Only simulated so the extra player doesn't change the vault totals checked at the end
+      return await context.banksClient.simulateTransaction(transaction);
+    }
+
+    before(async function () {
+      sessionPlayer = Keypair.generate();
+      sessionKey = Keypair.generate();
+
+      const transferTransaction = new anchor.web3.Transaction().add(
+        anchor.web3.SystemProgram.transfer({
+          fromPubkey: payer.publicKey,
+          toPubkey: sessionPlayer.publicKey,
+          lamports: 100_000_000,
+        })
       );
-      const initialVaultTokenAmount = initialGameVault.mintRemaining.toNumber();
+      await provider.sendAndConfirm(transferTransaction, [payer]);
 
-      for (let attempt = 0; attempt < maxAttempts; attempt++) {
-        advanceClockBySlots(context, NUM_SLOTS_COOLDOWN);
-        const playerTokenAccount = await token.getAccount(
-          provider.connection,
-          playerTokenAccountPDA
+      sessionPlayerTokenAccount = token.getAssociatedTokenAddressSync(
+        TOKEN_MINT,
+        sessionPlayer.publicKey
+      );
+      sessionPlayerProfilePDA = findPlayerProfilePDA(sessionPlayer.publicKey);
+      sessionPDA = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("session"),
+          sessionPlayer.publicKey.toBuffer(),
+          sessionKey.publicKey.toBuffer(),
+        ],
+        SPLIT_OR_STEAL_PROGRAM_ID
+      )[0];
+
+      const currentClock = await banksClient.getClock();
+      await splitOrSteal.methods
+        .createSession(
+          sessionKey.publicKey,
+          new BN((currentClock.slot + BigInt(1_000)).toString()),
+          new BN(0),
+          2
+        )
+        .accounts({
+          player: sessionPlayer.publicKey,
+          session: sessionPDA,
+          playerTokenAccount: sessionPlayerTokenAccount,
+          mint: TOKEN_MINT,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .preInstructions([
+          token.createAssociatedTokenAccountInstruction(
+            payer.publicKey,
+            sessionPlayerTokenAccount,
+            sessionPlayer.publicKey,
+            TOKEN_MINT
+          ),
+        ])
+        .signers([sessionPlayer])
+        .rpc();
+    });
+
+    it("lets the session key play without the player signing", async function () {
+      const simulationResult = await simulateSessionPlay(true);
+      assert.isNull(
+        simulationResult.result,
+        "Session key should be able to play for the player"
+      );
+    });
+
+    it("should fail if neither the player nor a session key signs", async function () {
+      const simulationResult = await simulateSessionPlay(false);
+      assert(
+        simulationResult.meta.logMessages.some((msg) =>
+          msg.includes("PlayerNotAuthorized")
+        ),
+        "Playing without any signature should cause an error"
+      );
+    });
+
+    it("should fail if the max total stake overflows", async function () {
+      const overflowSessionKey = Keypair.generate();
+      const overflowSessionPDA = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("session"),
+          sessionPlayer.publicKey.toBuffer(),
+          overflowSessionKey.publicKey.toBuffer(),
+        ],
+        SPLIT_OR_STEAL_PROGRAM_ID
+      )[0];
+
+      const currentClock = await banksClient.getClock();
+      try {
+        await splitOrSteal.methods
+          .createSession(
+            overflowSessionKey.publicKey,
+            new BN((currentClock.slot + BigInt(1_000)).toString()),
+            new BN("18446744073709551615"),
+            2
+          )
+          .accounts({
+            player: sessionPlayer.publicKey,
+            session: overflowSessionPDA,
+            playerTokenAccount: sessionPlayerTokenAccount,
+            mint: TOKEN_MINT,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([sessionPlayer])
+          .rpc();
+        assert.fail(
+          "The transaction should have failed due to the max total stake overflowing"
         );
-        const maxStake = Math.max(
-          1,
-          Math.min(100, Number(playerTokenAccount.amount) / 1_000_000_000)
+      } catch (err) {
+        assert.include(
+          err.message,
+          "InvalidSessionStake",
+          "An overflowing max total stake should cause an error"
         );
-        const stakeAmount = new BN(Math.floor(Math.random() * maxStake)); // Random stake amount between 0 and maxStake
+      }
+    });
+  });
 
-        if (
-          Number(playerTokenAccount.amount) <
-          stakeAmount.toNumber() * 1_000_000_000
-        ) {
-          continue; // Skip if player has insufficient tokens for this stake amount
-        }
+  describe("#play_batch", async function () {
+    it("plays every game in the batch and updates the profile per game", async function () {
+      advanceClockBySlots(context, NUM_SLOTS_COOLDOWN);
+      const games = [
+        { choice: { split: {} }, stakeAmount: 0 },
+        { choice: { steal: {} }, stakeAmount: 0 },
+        { choice: { split: {} }, stakeAmount: 1 },
+      ];
 
-        const choice = Math.random() > 0.5 ? { split: {} } : { steal: {} }; // Random choice between split and steal
+      const profileBefore = await splitOrSteal.account.playerProfile.fetch(
+        playerProfilePDA
+      );
 
-        // Get the playGame instruction
-        const playGameInstruction = await splitOrSteal.methods
-          .playGame(choice, stakeAmount)
+      await splitOrSteal.methods
+        .playBatch(games)
+        .accounts({
+          player: payer.publicKey,
+          payer: payer.publicKey,
+          playerProfile: playerProfilePDA,
+          profileTombstone: findProfileTombstonePDA(payer.publicKey),
+          legacyProfile: findLegacyProfilePDA(payer.publicKey),
+          playerTokenAccount: playerTokenAccountPDA,
+          gameVault: gameVaultPDA,
+          gameVaultTokenAccount: gameVaultTokenAccountPDA,
+          treasury: treasuryPDA,
+          mint: TOKEN_MINT,
+          slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
+          instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: token.ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .preInstructions([
+          anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({
+            units: 400_000,
+          }),
+        ])
+        .rpc();
+
+      const profileAfter = await splitOrSteal.account.playerProfile.fetch(
+        playerProfilePDA
+      );
+      assert.equal(profileAfter.numGames, profileBefore.numGames + games.length);
+      assert.equal(
+        profileAfter.stats.numSplits - profileBefore.stats.numSplits,
+        2
+      );
+      assert.equal(
+        profileAfter.stats.numSteals - profileBefore.stats.numSteals,
+        1
+      );
+    });
+
+    it("should fail if the batch is played again before the cooldown", async function () {
+      try {
+        await splitOrSteal.methods
+          .playBatch([{ choice: { split: {} }, stakeAmount: 0 }])
           .accounts({
             player: payer.publicKey,
+            payer: payer.publicKey,
             playerProfile: playerProfilePDA,
+            profileTombstone: findProfileTombstonePDA(payer.publicKey),
+            legacyProfile: findLegacyProfilePDA(payer.publicKey),
             playerTokenAccount: playerTokenAccountPDA,
             gameVault: gameVaultPDA,
             gameVaultTokenAccount: gameVaultTokenAccountPDA,
-            creator: payer.publicKey,
+            treasury: treasuryPDA,
+            mint: TOKEN_MINT,
+            slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
+            instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: token.ASSOCIATED_TOKEN_PROGRAM_ID,
+          })
+          .rpc();
+        assert.fail("The transaction should have failed due to the cooldown");
+      } catch (err) {
+        assert.include(
+          err.message,
+          "PlayedAgainTooSoon",
+          "Batch should respect the cooldown"
+        );
+      }
+    });
+
+    it("should fail if the batch is too large", async function () {
+      // The three game batch above pushed the cooldown back by three cooldowns
+      advanceClockBySlots(context, NUM_SLOTS_COOLDOWN * 3);
+      const games = Array.from({ length: 11 }, () => ({
+        choice: { split: {} },
+        stakeAmount: 0,
+      }));
+
+      try {
+        await splitOrSteal.methods
+          .playBatch(games)
+          .accounts({
+            player: payer.publicKey,
+            payer: payer.publicKey,
+            playerProfile: playerProfilePDA,
+            profileTombstone: findProfileTombstonePDA(payer.publicKey),
+            legacyProfile: findLegacyProfilePDA(payer.publicKey),
+            playerTokenAccount: playerTokenAccountPDA,
+            gameVault: gameVaultPDA,
+            gameVaultTokenAccount: gameVaultTokenAccountPDA,
+            treasury: treasuryPDA,
+            mint: TOKEN_MINT,
+            slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
+            instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: token.ASSOCIATED_TOKEN_PROGRAM_ID,
+          })
+          .rpc();
+        assert.fail("The transaction should have failed due to batch size");
+      } catch (err) {
+        assert.include(
+          err.message,
+          "BatchTooLarge",
+          "Batch over the maximum size should cause an error"
+        );
+      }
+    });
+  });
+
+  describe("#close_profile", async function () {
+    it("closes a profile and the re-created profile inherits its stats", async function () {
+      const closingPlayer = anchor.web3.Keypair.generate();
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          anchor.web3.SystemProgram.transfer({
+            fromPubkey: payer.publicKey,
+            toPubkey: closingPlayer.publicKey,
+            lamports: 1_000_000_000,
+          })
+        ),
+        [payer]
+      );
+
+      const closingProfilePDA = findPlayerProfilePDA(closingPlayer.publicKey);
+      const tombstonePDA = findProfileTombstonePDA(closingPlayer.publicKey);
+      const playAccounts = {
+        player: closingPlayer.publicKey,
+        payer: closingPlayer.publicKey,
+        playerProfile: closingProfilePDA,
+        profileTombstone: tombstonePDA,
+        legacyProfile: findLegacyProfilePDA(closingPlayer.publicKey),
+        playerTokenAccount: token.getAssociatedTokenAddressSync(
+          TOKEN_MINT,
+          closingPlayer.publicKey
+        ),
+        gameVault: gameVaultPDA,
+        gameVaultTokenAccount: gameVaultTokenAccountPDA,
+        treasury: treasuryPDA,
+        mint: TOKEN_MINT,
+        slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
+        instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: token.ASSOCIATED_TOKEN_PROGRAM_ID,
+      };
+
+      await splitOrSteal.methods
+        .playGame({ split: {} }, 0)
+        .accounts(playAccounts)
+        .signers([closingPlayer])
+        .rpc();
+
+      await splitOrSteal.methods
+        .closeProfile()
+        .accounts({
+          player: closingPlayer.publicKey,
+          gameVault: gameVaultPDA,
+          playerProfile: closingProfilePDA,
+          profileTombstone: tombstonePDA,
+          treasury: treasuryPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([closingPlayer])
+        .rpc();
+
+      assert.isNull(await banksClient.getAccount(closingProfilePDA));
+      const tombstone = await splitOrSteal.account.profileTombstone.fetch(
+        tombstonePDA
+      );
+      assert.equal(tombstone.numCloses, 1);
+      assert.equal(tombstone.numGames, 1);
+
+      advanceClockBySlots(context, NUM_SLOTS_COOLDOWN);
+      await splitOrSteal.methods
+        .playGame({ split: {} }, 0)
+        .accounts(playAccounts)
+        .signers([closingPlayer])
+        .rpc();
+
+      const recreatedProfile = await splitOrSteal.account.playerProfile.fetch(
+        closingProfilePDA
+      );
+      assert.equal(recreatedProfile.numGames, 2);
+    });
+  });
+
+  describe("#migrate_profile", async function () {
+    it("moves a profile in the originally deployed layout to the vault scoped address", async function () {
+      const migratingPlayer = anchor.web3.Keypair.generate();
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          anchor.web3.SystemProgram.transfer({
+            fromPubkey: payer.publicKey,
+            toPubkey: migratingPlayer.publicKey,
+            lamports: 1_000_000_000,
+          })
+        ),
+        [payer]
+      );
+
+      // Written by hand, the legacy layout isn't in the IDL anymore
+      const u64 = (value: number) => new BN(value).toArrayLike(Buffer, "le", 8);
+      const numGames = Buffer.alloc(4);
+      numGames.writeUInt32LE(40);
+      const legacyData = Buffer.concat([
+        anchor.BorshAccountsCoder.accountDiscriminator("PlayerProfile"),
+        Buffer.from([1, 255]), // is_initialized, bump
+        u64(1_234), // last_played_slot
+        u64(5_000_000_000), // tokens_gained
+        u64(2_000_000_000), // tokens_burned
+        numGames,
+        Buffer.from([3]), // num_split_bonuses
+        u64(600_000_000_000), // average_num_splits
+        u64(25_000_000_000), // average_stake_amount
+        Buffer.alloc(1), // Padding the original size_of based space left at the end
+      ]);
+      const legacyProfilePDA = findLegacyProfilePDA(migratingPlayer.publicKey);
+      context.setAccount(legacyProfilePDA, {
+        lamports: 10_000_000,
+        data: legacyData,
+        owner: SPLIT_OR_STEAL_PROGRAM_ID,
+        executable: false,
+      });
+
+      const migratedProfilePDA = findPlayerProfilePDA(
+        migratingPlayer.publicKey
+      );
+      const playerBalanceBefore = await banksClient.getBalance(
+        migratingPlayer.publicKey
+      );
+      // Relayed, the new profile's rent comes from the payer
+      await splitOrSteal.methods
+        .migrateProfile()
+        .accounts({
+          player: migratingPlayer.publicKey,
+          payer: payer.publicKey,
+          gameVault: gameVaultPDA,
+          legacyProfile: legacyProfilePDA,
+          playerProfile: migratedProfilePDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([migratingPlayer])
+        .rpc();
+
+      assert.isNull(await banksClient.getAccount(legacyProfilePDA));
+      // The legacy profile's rent goes back to the player, not the relayer
+      assert.equal(
+        await banksClient.getBalance(migratingPlayer.publicKey),
+        playerBalanceBefore + BigInt(10_000_000)
+      );
+      const migratedProfile = await splitOrSteal.account.playerProfile.fetch(
+        migratedProfilePDA
+      );
+      assert.isTrue(migratedProfile.isInitialized);
+      assert.equal(migratedProfile.lastPlayedSlot.toNumber(), 1_234);
+      assert.equal(migratedProfile.tokensGained.toNumber(), 5_000_000_000);
+      assert.equal(migratedProfile.tokensBurned.toNumber(), 2_000_000_000);
+      assert.equal(migratedProfile.numGames, 40);
+      assert.equal(migratedProfile.numSplitBonuses, 3);
+      assert.equal(
+        migratedProfile.averageNumSplits.toNumber(),
+        600_000_000_000
+      );
+      assert.equal(
+        migratedProfile.averageStakeAmount.toNumber(),
+        25_000_000_000
+      );
+      assert.isTrue(migratedProfile.referrer.equals(PublicKey.default));
+    });
+  });
+
+  describe("#player_history", async function () {
+    it("records the player's recent games once a history is set up", async function () {
+      const playerHistoryPDA = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("history"),
+          gameVaultPDA.toBuffer(),
+          payer.publicKey.toBuffer(),
+        ],
+        SPLIT_OR_STEAL_PROGRAM_ID
+      )[0];
+
+      await splitOrSteal.methods
+        .resizePlayerHistory(2)
+        .accounts({
+          player: payer.publicKey,
+          gameVault: gameVaultPDA,
+          playerHistory: playerHistoryPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      for (const choice of [{ split: {} }, { steal: {} }, { split: {} }]) {
+        advanceClockBySlots(context, NUM_SLOTS_COOLDOWN);
+        await splitOrSteal.methods
+          .playGame(choice, 0)
+          .accounts({
+            player: payer.publicKey,
+            payer: payer.publicKey,
+            playerProfile: playerProfilePDA,
+            profileTombstone: findProfileTombstonePDA(payer.publicKey),
+            legacyProfile: findLegacyProfilePDA(payer.publicKey),
+            playerTokenAccount: playerTokenAccountPDA,
+            gameVault: gameVaultPDA,
+            gameVaultTokenAccount: gameVaultTokenAccountPDA,
+            treasury: treasuryPDA,
+            mint: TOKEN_MINT,
+            slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
+            instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: token.ASSOCIATED_TOKEN_PROGRAM_ID,
+            playerHistory: playerHistoryPDA,
+          })
+          .rpc();
+      }
+
+      const history = await splitOrSteal.account.playerHistory.fetch(
+        playerHistoryPDA
+      );
+      assert.equal(history.capacity, 2);
+      assert.equal(history.entries.length, 2);
+      // The first game was overwritten by the third
+      assert.deepEqual(history.entries[0].playerChoice, { split: {} });
+      assert.deepEqual(history.entries[1].playerChoice, { steal: {} });
+
+      await splitOrSteal.methods
+        .resizePlayerHistory(5)
+        .accounts({
+          player: payer.publicKey,
+          gameVault: gameVaultPDA,
+          playerHistory: playerHistoryPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      const resized = await splitOrSteal.account.playerHistory.fetch(
+        playerHistoryPDA
+      );
+      assert.equal(resized.capacity, 5);
+      // Put back in order, oldest first
+      assert.deepEqual(resized.entries[0].playerChoice, { steal: {} });
+    });
+  });
+
+  describe("#claim_achievement", async function () {
+    const findAchievementCollectionPDA = () =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("achievement_collection"), gameVaultPDA.toBuffer()],
+        SPLIT_OR_STEAL_PROGRAM_ID
+      )[0];
+
+    it("initializes the achievement collection", async function () {
+      await splitOrSteal.methods
+        .initializeAchievementCollection()
+        .accounts({
+          creator: payer.publicKey,
+          gameVault: gameVaultPDA,
+          achievementCollection: findAchievementCollectionPDA(),
+          tokenProgram: token.TOKEN_2022_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const collection = await token.getMint(
+        provider.connection,
+        findAchievementCollectionPDA(),
+        "confirmed",
+        token.TOKEN_2022_PROGRAM_ID
+      );
+      const group = token.getTokenGroupState(collection);
+      assert.isTrue(group.updateAuthority.equals(gameVaultPDA));
+      assert.equal(group.size, 0);
+      assert.isTrue(
+        token
+          .getGroupPointerState(collection)
+          .groupAddress.equals(findAchievementCollectionPDA())
+      );
+    });
+
+    it("should fail to claim an achievement that hasn't been earned", async function () {
+      const achievementMintPDA = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("achievement"),
+          payer.publicKey.toBuffer(),
+          Buffer.from([1]), // ThousandGames
+        ],
+        SPLIT_OR_STEAL_PROGRAM_ID
+      )[0];
+
+      try {
+        await splitOrSteal.methods
+          .claimAchievement({ thousandGames: {} })
+          .accounts({
+            player: payer.publicKey,
+            playerProfile: playerProfilePDA,
+            gameVault: gameVaultPDA,
+            achievementMint: achievementMintPDA,
+            achievementCollection: findAchievementCollectionPDA(),
+            achievementTokenAccount: token.getAssociatedTokenAddressSync(
+              achievementMintPDA,
+              payer.publicKey,
+              true,
+              token.TOKEN_2022_PROGRAM_ID
+            ),
+            tokenProgram: token.TOKEN_2022_PROGRAM_ID,
+            associatedTokenProgram: token.ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        assert.fail("The transaction should have failed");
+      } catch (err) {
+        assert.include(
+          err.message,
+          "AchievementNotEarned",
+          "Only earned achievements can be claimed"
+        );
+      }
+    });
+
+    it("mints a non-transferable badge in the collection for an earned achievement", async function () {
+      // Earned by writing the flag straight into the profile,
+      // playing for it would change the vault totals checked at the end
+      const profileAccount = await banksClient.getAccount(playerProfilePDA);
+      const profile = await splitOrSteal.account.playerProfile.fetch(
+        playerProfilePDA
+      );
+      const profileData = await splitOrSteal.coder.accounts.encode(
+        "PlayerProfile",
+        { ...profile, achievements: profile.achievements | (1 << 2) }
+      );
+      context.setAccount(playerProfilePDA, {
+        ...profileAccount,
+        data: Buffer.concat([
+          profileData,
+          Buffer.alloc(profileAccount.data.length - profileData.length),
+        ]),
+      });
+
+      const achievementMintPDA = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("achievement"),
+          payer.publicKey.toBuffer(),
+          Buffer.from([2]), // TenSplitsInARow
+        ],
+        SPLIT_OR_STEAL_PROGRAM_ID
+      )[0];
+      const achievementTokenAccount = token.getAssociatedTokenAddressSync(
+        achievementMintPDA,
+        payer.publicKey,
+        true,
+        token.TOKEN_2022_PROGRAM_ID
+      );
+      // Anyone can fund the badge's address ahead of the claim, that mustn't block it
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          anchor.web3.SystemProgram.transfer({
+            fromPubkey: payer.publicKey,
+            toPubkey: achievementMintPDA,
+            lamports: 1,
+          })
+        ),
+        [payer]
+      );
+      await splitOrSteal.methods
+        .claimAchievement({ tenSplitsInARow: {} })
+        .accounts({
+          player: payer.publicKey,
+          playerProfile: playerProfilePDA,
+          gameVault: gameVaultPDA,
+          achievementMint: achievementMintPDA,
+          achievementCollection: findAchievementCollectionPDA(),
+          achievementTokenAccount,
+          tokenProgram: token.TOKEN_2022_PROGRAM_ID,
+          associatedTokenProgram: token.ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const badgeMint = await token.getMint(
+        provider.connection,
+        achievementMintPDA,
+        "confirmed",
+        token.TOKEN_2022_PROGRAM_ID
+      );
+      assert.equal(Number(badgeMint.supply), 1);
+      assert.equal(badgeMint.decimals, 0);
+      assert.isNull(badgeMint.mintAuthority);
+      assert.isNotNull(token.getNonTransferable(badgeMint));
+      const metadataPointer = token.getMetadataPointerState(badgeMint);
+      assert.isTrue(metadataPointer.metadataAddress.equals(achievementMintPDA));
+      assert.isTrue(metadataPointer.authority.equals(gameVaultPDA));
+      const member = token.getTokenGroupMemberState(badgeMint);
+      assert.isTrue(member.group.equals(findAchievementCollectionPDA()));
+      assert.equal(member.memberNumber, 1);
+
+      const badgeAccount = await token.getAccount(
+        provider.connection,
+        achievementTokenAccount,
+        "confirmed",
+        token.TOKEN_2022_PROGRAM_ID
+      );
+      assert.equal(Number(badgeAccount.amount), 1);
+    });
+  });
+
+  describe("#set_profile_metadata", async function () {
+    const findNicknameRecordPDA = (nickname: string) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("nickname"), Buffer.from(nickname.toLowerCase())],
+        SPLIT_OR_STEAL_PROGRAM_ID
+      )[0];
+    let profileMetadataPDA: PublicKey;
+
+    it("sets a nickname and registers it", async function () {
+      profileMetadataPDA = PublicKey.findProgramAddressSync(
+        [Buffer.from("metadata"), payer.publicKey.toBuffer()],
+        SPLIT_OR_STEAL_PROGRAM_ID
+      )[0];
+
+      await splitOrSteal.methods
+        .setProfileMetadata("Gourd", "https://example.com/gourd.png", true)
+        .accounts({
+          player: payer.publicKey,
+          gameVault: gameVaultPDA,
+          playerProfile: playerProfilePDA,
+          profileMetadata: profileMetadataPDA,
+          nicknameRecord: findNicknameRecordPDA("Gourd"),
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const metadata = await splitOrSteal.account.profileMetadata.fetch(
+        profileMetadataPDA
+      );
+      assert.equal(
+        Buffer.from(metadata.nickname).toString().replace(/\0+$/, ""),
+        "Gourd"
+      );
+      assert.isTrue(metadata.showOnLeaderboard);
+      const record = await splitOrSteal.account.nicknameRecord.fetch(
+        findNicknameRecordPDA("gourd")
+      );
+      assert.isTrue(record.player.equals(payer.publicKey));
+    });
+
+    it("should fail to change nickname without freeing the old one", async function () {
+      try {
+        await splitOrSteal.methods
+          .setProfileMetadata("Pumpkin", "", false)
+          .accounts({
+            player: payer.publicKey,
+            gameVault: gameVaultPDA,
+            playerProfile: playerProfilePDA,
+            profileMetadata: profileMetadataPDA,
+            nicknameRecord: findNicknameRecordPDA("Pumpkin"),
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        assert.fail("The transaction should have failed");
+      } catch (err) {
+        assert.include(err.message, "PreviousNicknameRecordMissing");
+      }
+
+      await splitOrSteal.methods
+        .setProfileMetadata("Pumpkin", "", false)
+        .accounts({
+          player: payer.publicKey,
+          gameVault: gameVaultPDA,
+          playerProfile: playerProfilePDA,
+          profileMetadata: profileMetadataPDA,
+          nicknameRecord: findNicknameRecordPDA("Pumpkin"),
+          previousNicknameRecord: findNicknameRecordPDA("Gourd"),
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      assert.isNull(
+        await banksClient.getAccount(findNicknameRecordPDA("Gourd"))
+      );
+    });
+  });
+
+  describe("#link_wallet", async function () {
+    it("plays a linked wallet's games on the primary's profile", async function () {
+      const primary = anchor.web3.Keypair.generate();
+      const secondary = anchor.web3.Keypair.generate();
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          ...[primary, secondary].map((wallet) =>
+            anchor.web3.SystemProgram.transfer({
+              fromPubkey: payer.publicKey,
+              toPubkey: wallet.publicKey,
+              lamports: 1_000_000_000,
+            })
+          )
+        ),
+        [payer]
+      );
+
+      const findProfilePDA = (wallet: Keypair) =>
+        findPlayerProfilePDA(wallet.publicKey);
+      const playAccounts = (wallet: Keypair) => ({
+        player: wallet.publicKey,
+        payer: wallet.publicKey,
+        playerProfile: findProfilePDA(wallet),
+        profileTombstone: findProfileTombstonePDA(wallet.publicKey),
+        legacyProfile: findLegacyProfilePDA(wallet.publicKey),
+        playerTokenAccount: token.getAssociatedTokenAddressSync(
+          TOKEN_MINT,
+          wallet.publicKey
+        ),
+        gameVault: gameVaultPDA,
+        gameVaultTokenAccount: gameVaultTokenAccountPDA,
+        treasury: treasuryPDA,
+        mint: TOKEN_MINT,
+        slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
+        instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: token.ASSOCIATED_TOKEN_PROGRAM_ID,
+      });
+
+      for (const wallet of [primary, secondary]) {
+        await splitOrSteal.methods
+          .playGame({ split: {} }, 0)
+          .accounts(playAccounts(wallet))
+          .signers([wallet])
+          .rpc();
+      }
+
+      // Not linked yet, so there's no other profile to play on
+      try {
+        await splitOrSteal.methods
+          .playGame({ split: {} }, 0)
+          .accounts({
+            ...playAccounts(secondary),
+            linkedProfile: findProfilePDA(primary),
+          })
+          .signers([secondary])
+          .rpc();
+        assert.fail("The transaction should have failed");
+      } catch (err) {
+        assert.include(err.message, "WalletNotLinked");
+      }
+
+      await splitOrSteal.methods
+        .linkWallet()
+        .accounts({
+          primary: primary.publicKey,
+          gameVault: gameVaultPDA,
+          primaryProfile: findProfilePDA(primary),
+          secondary: secondary.publicKey,
+          secondaryProfile: findProfilePDA(secondary),
+        })
+        .signers([primary, secondary])
+        .rpc();
+
+      let primaryProfile = await splitOrSteal.account.playerProfile.fetch(
+        findProfilePDA(primary)
+      );
+      assert.equal(primaryProfile.numGames, 2);
+      assert.equal(primaryProfile.numLinkedWallets, 1);
+
+      advanceClockBySlots(context, NUM_SLOTS_COOLDOWN);
+      try {
+        await splitOrSteal.methods
+          .playGame({ split: {} }, 0)
+          .accounts(playAccounts(secondary))
+          .signers([secondary])
+          .rpc();
+        assert.fail("The transaction should have failed");
+      } catch (err) {
+        assert.include(err.message, "LinkedProfileMissing");
+      }
+
+      await splitOrSteal.methods
+        .playGame({ split: {} }, 0)
+        .accounts({
+          ...playAccounts(secondary),
+          linkedProfile: findProfilePDA(primary),
+        })
+        .signers([secondary])
+        .rpc();
+
+      primaryProfile = await splitOrSteal.account.playerProfile.fetch(
+        findProfilePDA(primary)
+      );
+      assert.equal(primaryProfile.numGames, 3);
+      const secondaryProfile = await splitOrSteal.account.playerProfile.fetch(
+        findProfilePDA(secondary)
+      );
+      assert.equal(secondaryProfile.numGames, 1);
+    });
+  });
+
+  describe("#start_season", async function () {
+    it("archives the ending season and starts the player season stats over", async function () {
+      const player = await createFundedPlayer();
+      await playGame(player);
+
+      const vault = await splitOrSteal.account.gameVault.fetch(gameVaultPDA);
+      let profile = await splitOrSteal.account.playerProfile.fetch(
+        findPlayerProfilePDA(player.publicKey)
+      );
+      assert.equal(profile.seasonStats.season, vault.season);
+      assert.equal(profile.seasonStats.numGames, 1);
+
+      const seasonArchivePDA = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("season"),
+          gameVaultPDA.toBuffer(),
+          new BN(vault.season).toArrayLike(Buffer, "le", 4),
+        ],
+        SPLIT_OR_STEAL_PROGRAM_ID
+      )[0];
+      await splitOrSteal.methods
+        .startSeason()
+        .accounts({
+          creator: payer.publicKey,
+          gameVault: gameVaultPDA,
+          seasonArchive: seasonArchivePDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const archive = await splitOrSteal.account.seasonArchive.fetch(
+        seasonArchivePDA
+      );
+      assert.equal(archive.season, vault.season);
+      assert.equal(
+        archive.startSlot.toNumber(),
+        vault.seasonStartSlot.toNumber()
+      );
+      assert.equal(
+        archive.endSlot.toString(),
+        (await banksClient.getClock()).slot.toString()
+      );
+      assert.equal(
+        archive.numGames.toNumber(),
+        vault.seasonNumGames.toNumber()
+      );
+      assert.equal(archive.numSplits, vault.numSplits);
+      assert.equal(archive.numSteals, vault.numSteals);
+      assert.equal(
+        archive.mintRemaining.toNumber(),
+        vault.mintRemaining.toNumber()
+      );
+      assert.equal(
+        archive.burnedAmount.toNumber(),
+        vault.burnedAmount.toNumber()
+      );
+
+      const newVault = await splitOrSteal.account.gameVault.fetch(gameVaultPDA);
+      assert.equal(newVault.season, vault.season + 1);
+      assert.equal(newVault.seasonNumGames.toNumber(), 0);
+
+      // The player's first game of the new season starts their season stats over, lifetime stats carry on
+      await warpBySlots(NUM_SLOTS_COOLDOWN);
+      await playGame(player);
+      profile = await splitOrSteal.account.playerProfile.fetch(
+        findPlayerProfilePDA(player.publicKey)
+      );
+      assert.equal(profile.seasonStats.season, vault.season + 1);
+      assert.equal(profile.seasonStats.numGames, 1);
+      assert.equal(profile.numGames, 2);
+    });
+  });
+
+  describe("#teams", async function () {
+    it("records a member's games on their team and ranks the team", async function () {
+      const leader = await createFundedPlayer();
+      const member = await createFundedPlayer();
+      for (const player of [leader, member]) {
+        await playGame(player);
+      }
+
+      const teamPDA = PublicKey.findProgramAddressSync(
+        [Buffer.from("team"), Buffer.from("gourds")],
+        SPLIT_OR_STEAL_PROGRAM_ID
+      )[0];
+      await splitOrSteal.methods
+        .createTeam("gourds", 2)
+        .accounts({
+          leader: leader.publicKey,
+          gameVault: gameVaultPDA,
+          leaderProfile: findPlayerProfilePDA(leader.publicKey),
+          team: teamPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([leader])
+        .rpc();
+      await splitOrSteal.methods
+        .joinTeam()
+        .accounts({
+          player: member.publicKey,
+          gameVault: gameVaultPDA,
+          playerProfile: findPlayerProfilePDA(member.publicKey),
+          team: teamPDA,
+        })
+        .signers([member])
+        .rpc();
+
+      let team = await splitOrSteal.account.team.fetch(teamPDA);
+      assert.equal(team.numMembers, 2);
+      assert.equal(team.numGames.toNumber(), 0);
+
+      // Team members have to bring the team along to play
+      await warpBySlots(NUM_SLOTS_COOLDOWN);
+      try {
+        await playGame(member);
+        assert.fail("The transaction should have failed");
+      } catch (err) {
+        assert.include(err.message, "TeamAccountMissing");
+      }
+
+      await playGame(member, { team: teamPDA });
+      team = await splitOrSteal.account.team.fetch(teamPDA);
+      assert.equal(team.numGames.toNumber(), 1);
+      assert.equal(team.numSplits.toNumber(), 1);
+
+      // Straight back out isn't allowed, members can't hop between teams mid competition
+      try {
+        await splitOrSteal.methods
+          .leaveTeam()
+          .accounts({
+            player: member.publicKey,
+            gameVault: gameVaultPDA,
+            playerProfile: findPlayerProfilePDA(member.publicKey),
+            team: teamPDA,
+          })
+          .signers([member])
+          .rpc();
+        assert.fail("The transaction should have failed");
+      } catch (err) {
+        assert.include(err.message, "TeamChangeTooSoon");
+      }
+
+      const teamLeaderboardPDA = PublicKey.findProgramAddressSync(
+        [Buffer.from("team leaderboard"), gameVaultPDA.toBuffer()],
+        SPLIT_OR_STEAL_PROGRAM_ID
+      )[0];
+      await splitOrSteal.methods
+        .initializeTeamLeaderboard()
+        .accounts({
+          creator: payer.publicKey,
+          gameVault: gameVaultPDA,
+          teamLeaderboard: teamLeaderboardPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      await splitOrSteal.methods
+        .updateTeamLeaderboard()
+        .accounts({
+          gameVault: gameVaultPDA,
+          teamLeaderboard: teamLeaderboardPDA,
+          team: teamPDA,
+        })
+        .rpc();
+
+      const leaderboard = await splitOrSteal.account.teamLeaderboard.fetch(
+        teamLeaderboardPDA
+      );
+      assert.equal(leaderboard.numEntries, 1);
+      assert.isTrue(leaderboard.entries[0].team.equals(teamPDA));
+      assert.equal(
+        leaderboard.entries[0].score.toNumber(),
+        team.tokensGained.toNumber() - team.tokensBurned.toNumber()
+      );
+    });
+  });
+
+  describe("#play_throttle", async function () {
+    it("should fail once the vault wide plays for the slot are used up", async function () {
+      const firstPlayer = await createFundedPlayer();
+      const secondPlayer = await createFundedPlayer();
+      const { config } = await splitOrSteal.account.gameVault.fetch(
+        gameVaultPDA
+      );
+      await updateConfig({ ...config, maxPlaysPerSlot: 1 });
+      try {
+        // A slot nobody has played in yet
+        await warpBySlots(1);
+        await playGame(firstPlayer);
+        try {
+          await playGame(secondPlayer);
+          assert.fail("The transaction should have failed");
+        } catch (err) {
+          assert.include(
+            err.message,
+            "SlotPlayLimitReached",
+            "Separate profiles share the vault wide limit"
+          );
+        }
+
+        // The next slot starts counting again
+        await warpBySlots(1);
+        await playGame(secondPlayer);
+      } finally {
+        await updateConfig(config);
+      }
+    });
+  });
+
+  describe("#burn_rate_lockout", async function () {
+    it("locks out a player failing the burn rate check for longer every time", async function () {
+      const player = await createFundedPlayer();
+      await playGame(player);
+      const profilePDA = findPlayerProfilePDA(player.publicKey);
+
+      // A hundred games of 50 token stakes at even odds without a single token burned
+      await patchAccount(profilePDA, "PlayerProfile", {
+        numGames: 100,
+        averageNumSplits: new BN(500_000_000_000),
+        averageStakeAmount: new BN(50_000_000_000),
+        tokensBurned: new BN(0),
+      });
+      // Played as a loss, with nothing staked there's nothing to burn
+      await warpBySlots(NUM_SLOTS_COOLDOWN);
+      await playGame(player);
+
+      const flaggedSlot = Number((await banksClient.getClock()).slot);
+      let profile = await splitOrSteal.account.playerProfile.fetch(profilePDA);
+      assert.equal(profile.burnRateCheckFailures, 1);
+      assert.equal(profile.flaggedSlot.toNumber(), flaggedSlot);
+      assert.equal(
+        profile.lockedUntilSlot.toNumber(),
+        flaggedSlot + BURN_RATE_LOCKOUT_SLOTS
+      );
+
+      // Past the cooldown but not the lockout
+      await warpBySlots(NUM_SLOTS_COOLDOWN);
+      try {
+        await playGame(player);
+        assert.fail("The transaction should have failed");
+      } catch (err) {
+        assert.include(err.message, "BurnRateLockout");
+      }
+
+      // Still failing once the lockout is served, so the next one is twice as long
+      await warpBySlots(BURN_RATE_LOCKOUT_SLOTS);
+      await playGame(player);
+      profile = await splitOrSteal.account.playerProfile.fetch(profilePDA);
+      assert.equal(profile.burnRateCheckFailures, 2);
+      assert.equal(
+        profile.lockedUntilSlot.toNumber() - profile.flaggedSlot.toNumber(),
+        2 * BURN_RATE_LOCKOUT_SLOTS
+      );
+    });
+  });
+
+  describe("#winnings_check", async function () {
+    it("locks out a player paid far more than their games were expected to pay", async function () {
+      const player = await createFundedPlayer();
+      await playGame(player);
+      const profilePDA = findPlayerProfilePDA(player.publicKey);
+
+      // Burned plenty so the burn rate check passes, but won 1000 tokens where 100 were expected
+      await patchAccount(profilePDA, "PlayerProfile", {
+        numGames: 100,
+        averageNumSplits: new BN(500_000_000_000),
+        averageStakeAmount: new BN(1_000_000_000),
+        tokensBurned: new BN(100_000_000_000_000),
+        tokensGained: new BN(1_000_000_000_000),
+        luckNumGames: 100,
+        luckExpectedGained: new BN(100_000_000_000),
+        luckActualGained: new BN(1_000_000_000_000),
+        luckVariance: new BN(10).pow(new BN(20)), // A standard deviation of 10 tokens
+      });
+      await warpBySlots(NUM_SLOTS_COOLDOWN);
+
+      const { events } = await simulatePlay(player);
+      const failedCheck = events.find(
+        (event) => event.name === "BurnRateCheckFailed"
+      );
+      assert.isDefined(failedCheck, "The player should have been flagged");
+      assert.isTrue(failedCheck.data.failedWinningsCheck);
+      assert.isFalse(failedCheck.data.failedBurnRateCheck);
+      assert.isFalse(failedCheck.data.failedSprtCheck);
+
+      await playGame(player);
+      const profile = await splitOrSteal.account.playerProfile.fetch(
+        profilePDA
+      );
+      assert.equal(profile.burnRateCheckFailures, 1);
+      // Played as a loss, so nothing more was won
+      assert.equal(profile.luckActualGained.toNumber(), 1_000_000_000_000);
+    });
+  });
+
+  describe("#sprt_luck_check", async function () {
+    it("locks out a player the SPRT flags and starts their test over", async function () {
+      const player = await createFundedPlayer();
+      const profilePDA = findPlayerProfilePDA(player.publicKey);
+      const { config } = await splitOrSteal.account.gameVault.fetch(
+        gameVaultPDA
+      );
+      await updateConfig({
+        ...config,
+        sprtOddsRatioBps: 20_000, // Twice the odds
+        sprtAlphaBps: 100,
+        sprtBetaBps: 100,
+      });
+      try {
+        await playGame(player);
+
+        // Well over the upper boundary, about 5.3 for these rates
+        await patchAccount(profilePDA, "PlayerProfile", {
+          luckSprt: {
+            logLikelihoodRatio: new BN(10_000_000_000),
+            numGames: 50,
+            numRestarts: 0,
+          },
+        });
+        await warpBySlots(NUM_SLOTS_COOLDOWN);
+
+        const { events } = await simulatePlay(player);
+        const failedCheck = events.find(
+          (event) => event.name === "BurnRateCheckFailed"
+        );
+        assert.isDefined(failedCheck, "The player should have been flagged");
+        assert.isTrue(failedCheck.data.failedSprtCheck);
+        assert.isFalse(failedCheck.data.failedBurnRateCheck);
+        assert.isFalse(failedCheck.data.failedWinningsCheck);
+
+        await playGame(player);
+        let profile = await splitOrSteal.account.playerProfile.fetch(
+          profilePDA
+        );
+        assert.equal(profile.burnRateCheckFailures, 1);
+        assert.equal(profile.luckSprt.logLikelihoodRatio.toNumber(), 0);
+        assert.equal(profile.luckSprt.numRestarts, 1);
+        // The flagged game was played as a loss and doesn't count towards the test
+        assert.equal(profile.luckSprt.numGames, 50);
+
+        // Served the lockout, the new test has nothing against them yet
+        await warpBySlots(BURN_RATE_LOCKOUT_SLOTS);
+        const recovered = await simulatePlay(player);
+        assert.isUndefined(
+          recovered.events.find((event) => event.name === "BurnRateCheckFailed")
+        );
+        await playGame(player);
+        profile = await splitOrSteal.account.playerProfile.fetch(profilePDA);
+        assert.equal(profile.burnRateCheckFailures, 1);
+        assert.equal(profile.luckSprt.numGames, 51);
+      } finally {
+        await updateConfig(config);
+      }
+    });
+  });
+
+  describe("#split_bonus_confidence", async function () {
+    it("holds back fewer split bonuses at a higher configured confidence", async function () {
+      const player = await createFundedPlayer();
+      await playGame(player);
+      const profilePDA = findPlayerProfilePDA(player.publicKey);
+      const { config } = await splitOrSteal.account.gameVault.fetch(
+        gameVaultPDA
+      );
+
+      try {
+        await updateConfig({ ...config, splitBonusConfidenceBps: 10_000 });
+        assert.fail("The transaction should have failed");
+      } catch (err) {
+        assert.include(
+          err.message,
+          "InvalidBasisPoints",
+          "Full confidence would never allow another bonus"
+        );
+      }
+
+      // Eligibility is only checked when the player wins a split, so keep playing until the opponent splits
+      async function playUntilOpponentSplits() {
+        for (let attempt = 0; attempt < 50; attempt++) {
+          // A second bonus within 41 games is about a 0.08% chance
+          await patchAccount(profilePDA, "PlayerProfile", {
+            numGames: 40,
+            numSplitBonuses: 1,
+          });
+          await warpBySlots(NUM_SLOTS_COOLDOWN);
+          const { logMessages } = await simulatePlay(player);
+          await playGame(player);
+          if (
+            logMessages.some((msg) => msg.includes("Opponent Choice: Split"))
+          ) {
+            return logMessages;
+          }
+        }
+        assert.fail("The opponent never split");
+      }
+      const isTooLucky = (logMessages: string[]) =>
+        logMessages.some((msg) =>
+          msg.includes("Player currently too lucky to get a split bonus")
+        );
+
+      // Under the default 1% minimum chance
+      assert.isTrue(isTooLucky(await playUntilOpponentSplits()));
+
+      // But over the 0.01% minimum at 99.99% confidence
+      await updateConfig({ ...config, splitBonusConfidenceBps: 9_999 });
+      try {
+        assert.isFalse(isTooLucky(await playUntilOpponentSplits()));
+      } finally {
+        await updateConfig(config);
+      }
+    });
+  });
+
+  describe("#anomaly_auto_pause", async function () {
+    it("pauses the vault on a vault wide payout anomaly until the creator unpauses it", async function () {
+      const player = await createFundedPlayer();
+      let vault = await splitOrSteal.account.gameVault.fetch(gameVaultPDA);
+      const config = vault.config;
+      await updateConfig({
+        ...config,
+        anomalyPauseZScore: new BN(4_000_000_000), // 4 sigma
+        anomalyMinGames: new BN(100),
+      });
+      try {
+        // Paid out 1000 tokens across the vault where 100 were expected, give or take 10
+        await patchAccount(gameVaultPDA, "GameVault", {
+          anomalyStats: {
+            ...vault.anomalyStats,
+            numGames: new BN(100),
+            expectedPayout: new BN(100_000_000_000),
+            actualPayout: new BN(1_000_000_000_000),
+            payoutVariance: new BN(10).pow(new BN(20)),
+          },
+        });
+
+        const { events } = await simulatePlay(player);
+        const autoPaused = events.find(
+          (event) => event.name === "VaultAutoPaused"
+        );
+        assert.isDefined(autoPaused, "The vault should have been paused");
+        assert.deepEqual(autoPaused.data.anomaly, { payouts: {} });
+
+        // The game that found the anomaly still goes through, only the games after it are stopped
+        await playGame(player);
+        vault = await splitOrSteal.account.gameVault.fetch(gameVaultPDA);
+        assert.isTrue(vault.paused);
+
+        await warpBySlots(NUM_SLOTS_COOLDOWN);
+        try {
+          await playGame(player);
+          assert.fail("The transaction should have failed");
+        } catch (err) {
+          assert.include(err.message, "VaultPaused");
+        }
+
+        // Starting the stats over so the old anomaly doesn't pause it again straight away
+        await splitOrSteal.methods
+          .setVaultPaused(false, true)
+          .accounts({ creator: payer.publicKey, gameVault: gameVaultPDA })
+          .rpc();
+        vault = await splitOrSteal.account.gameVault.fetch(gameVaultPDA);
+        assert.isFalse(vault.paused);
+        assert.equal(vault.anomalyStats.numGames.toNumber(), 0);
+        await playGame(player);
+      } finally {
+        await updateConfig(config);
+      }
+    });
+  });
+
+  describe("#claim_deposit", async function () {
+    it("holds the profile fee as a deposit until the player has played enough games", async function () {
+      const player = await createFundedPlayer();
+      const profilePDA = findPlayerProfilePDA(player.publicKey);
+      const { config } = await splitOrSteal.account.gameVault.fetch(
+        gameVaultPDA
+      );
+      await updateConfig({
+        ...config,
+        profileFeeAsDeposit: true,
+        depositRefundMinGames: 10,
+      });
+      try {
+        await playGame(player);
+        let profile = await splitOrSteal.account.playerProfile.fetch(
+          profilePDA
+        );
+        assert.equal(profile.depositLamports.toNumber(), 6_000_000);
+        assert.isTrue(profile.depositor.equals(player.publicKey));
+
+        const claimAccounts = {
+          player: player.publicKey,
+          gameVault: gameVaultPDA,
+          playerProfile: profilePDA,
+          depositor: player.publicKey,
+        };
+        try {
+          await splitOrSteal.methods
+            .claimDeposit()
+            .accounts(claimAccounts)
+            .signers([player])
+            .rpc();
+          assert.fail("The transaction should have failed");
+        } catch (err) {
+          assert.include(err.message, "DepositNotClaimable");
+        }
+
+        // Enough games at an even split rate to pass the significance test
+        await patchAccount(profilePDA, "PlayerProfile", {
+          numGames: 10,
+          averageNumSplits: new BN(500_000_000_000),
+        });
+        const balanceBefore = await banksClient.getBalance(player.publicKey);
+        await splitOrSteal.methods
+          .claimDeposit()
+          .accounts(claimAccounts)
+          .signers([player])
+          .rpc();
+        const balanceAfter = await banksClient.getBalance(player.publicKey);
+        assert.equal(Number(balanceAfter - balanceBefore), 6_000_000);
+        profile = await splitOrSteal.account.playerProfile.fetch(profilePDA);
+        assert.equal(profile.depositLamports.toNumber(), 0);
+      } finally {
+        await updateConfig(config);
+      }
+    });
+  });
+
+  describe("#play_game_run_out_clock", async function () {
+    it("should run transactions with random stake amounts and check final balances", async function () {
+      const maxAttempts = 5000; // Number of attempts to simulate
+
+      // Get initial balances
+      const initialPlayerTokenAccount = await token.getAccount(
+        provider.connection,
+        playerTokenAccountPDA
+      );
+      const initialPlayerTokenAmount = Number(initialPlayerTokenAccount.amount);
+      const initialGameVault = await splitOrSteal.account.gameVault.fetch(
+        gameVaultPDA
+      );
+      const initialVaultTokenAmount = initialGameVault.mintRemaining.toNumber();
+
+      for (let attempt = 0; attempt < maxAttempts; attempt++) {
+        advanceClockBySlots(context, NUM_SLOTS_COOLDOWN);
+        const playerTokenAccount = await token.getAccount(
+          provider.connection,
+          playerTokenAccountPDA
+        );
+        const maxStake = Math.max(
+          1,
+          Math.min(100, Number(playerTokenAccount.amount) / 1_000_000_000)
+        );
+        const stakeAmount = new BN(Math.floor(Math.random() * maxStake)); // Random stake amount between 0 and maxStake
+
+        if (
+          Number(playerTokenAccount.amount) <
+          stakeAmount.toNumber() * 1_000_000_000
+        ) {
+          continue; // Skip if player has insufficient tokens for this stake amount
+        }
+
+        const choice = Math.random() > 0.5 ? { split: {} } : { steal: {} }; // Random choice between split and steal
+
+        // Get the playGame instruction
+        const playGameInstruction = await splitOrSteal.methods
+          .playGame(choice, stakeAmount)
+          .accounts({
+            player: payer.publicKey,
+            payer: payer.publicKey,
+            playerProfile: playerProfilePDA,
+            profileTombstone: findProfileTombstonePDA(payer.publicKey),
+            legacyProfile: findLegacyProfilePDA(payer.publicKey),
+            playerTokenAccount: playerTokenAccountPDA,
+            gameVault: gameVaultPDA,
+            gameVaultTokenAccount: gameVaultTokenAccountPDA,
+            treasury: treasuryPDA,
             mint: TOKEN_MINT,
             slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
             instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,